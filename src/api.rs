@@ -1,17 +1,29 @@
 use serde::de::DeserializeOwned;
 
+use crate::cache::{Cache, CachePolicy};
+use crate::calendar::{EarningsCalendarBuilder, EarningsCalendarHorizon, IpoCalendarBuilder};
+#[cfg(feature = "blocking")]
+use crate::client::{BlockingHttpClient, BlockingWrapper};
 use crate::client::HttpClient;
 use crate::crypto::{CryptoBuilder, CryptoFunction};
 use crate::custom::CustomBuilder;
 use crate::earning::EarningBuilder;
-use crate::economic_indicator::EconomicIndicatorBuilder;
-use crate::error::{Error, Result};
+use crate::economic_indicator::{EconomicIndicatorBuilder, EconomicIndicatorFunction};
+use crate::error::{decode_csv, decode_json, is_message_response, Result};
 use crate::exchange::ExchangeBuilder;
 use crate::forex::{ForexBuilder, ForexFunction};
+use crate::listing::ListingStatusBuilder;
+use crate::news::NewsSentimentBuilder;
+use crate::overview::CompanyOverviewBuilder;
 use crate::quote::QuoteBuilder;
+use crate::rate_limit::RateLimiter;
+use crate::retry::RetryPolicy;
 use crate::search::SearchBuilder;
+use crate::statement::{BalanceSheetBuilder, CashFlowBuilder, IncomeStatementBuilder};
 use crate::stock_time::{StockFunction, TimeSeriesBuilder};
-use crate::technical_indicator::{TechnicalIndicatorBuilder, TechnicalIndicatorInterval};
+use crate::technical_indicator::{
+    TechnicalIndicatorBuilder, TechnicalIndicatorFunction, TechnicalIndicatorInterval,
+};
 
 const BASE_URL: &str = "https://www.alphavantage.co/";
 const RAPID_API_BASE_URL: &str = "https://alpha-vantage.p.rapidapi.com/query";
@@ -20,7 +32,7 @@ const RAPID_API_BASE_URL: &str = "https://alpha-vantage.p.rapidapi.com/query";
 pub enum Provider {
     /// Use alphavantage API provider
     AlphaVantage,
-    /// User RapidAPI as provider
+    /// User `RapidAPI` as provider
     RapidAPI,
 }
 
@@ -29,6 +41,9 @@ pub struct ApiClient {
     api: String,
     client: Box<dyn HttpClient + Send + Sync>,
     provider: Provider,
+    rate_limiter: Option<RateLimiter>,
+    retry_policy: Option<RetryPolicy>,
+    cache: Option<(Box<dyn Cache + Send + Sync>, CachePolicy)>,
 }
 
 impl ApiClient {
@@ -49,6 +64,9 @@ impl ApiClient {
             api: api.into(),
             client: Box::new(client),
             provider: Provider::AlphaVantage,
+            rate_limiter: None,
+            retry_policy: None,
+            cache: None,
         }
     }
 
@@ -69,9 +87,57 @@ impl ApiClient {
             api: api.into(),
             client: Box::new(client),
             provider: Provider::RapidAPI,
+            rate_limiter: None,
+            retry_policy: None,
+            cache: None,
         }
     }
 
+    /// Method for initializing `ApiClient` struct using user provided blocking
+    /// client and alphavantage.co provider. `json_blocking` method of builder
+    /// can be used to get data without async runtime
+    ///
+    /// ```
+    /// use alpha_vantage::api::ApiClient;
+    /// let api = ApiClient::set_blocking_api("some_key", reqwest::blocking::Client::new());
+    /// ```
+    ///
+    /// Client which does not require async runtime such as `MockClient` can
+    /// also be used with `json_blocking`
+    ///
+    /// ```
+    /// let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    /// let api = alpha_vantage::set_api("demo", client);
+    /// let quote = api.quote("MSFT").json_blocking().unwrap();
+    /// assert_eq!(quote.symbol(), "MSFT");
+    /// ```
+    #[cfg(feature = "blocking")]
+    #[must_use]
+    pub fn set_blocking_api<S, T>(api: S, client: T) -> Self
+    where
+        S: Into<String>,
+        T: BlockingHttpClient + 'static + Send + Sync,
+    {
+        Self::set_api(api, BlockingWrapper(client))
+    }
+
+    /// Method for initializing `ApiClient` struct using user provided blocking
+    /// client and `RapidAPI` API provider
+    ///
+    /// ```
+    /// use alpha_vantage::api::ApiClient;
+    /// let api = ApiClient::set_blocking_rapid_api("some_key", reqwest::blocking::Client::new());
+    /// ```
+    #[cfg(feature = "blocking")]
+    #[must_use]
+    pub fn set_blocking_rapid_api<S, T>(api: S, client: T) -> Self
+    where
+        S: Into<String>,
+        T: BlockingHttpClient + 'static + Send + Sync,
+    {
+        Self::set_rapid_api(api, BlockingWrapper(client))
+    }
+
     /// Method to get api key
     ///
     /// ```
@@ -84,12 +150,113 @@ impl ApiClient {
         &self.api
     }
 
+    /// Method to set rate limiter which is waited on before every API call so
+    /// all builders stay within quota of API key
+    ///
+    /// ```
+    /// use alpha_vantage::api::ApiClient;
+    /// use alpha_vantage::rate_limit::RateLimiter;
+    /// let api = ApiClient::set_api("some_key", reqwest::Client::new())
+    ///     .rate_limiter(RateLimiter::new(5).daily_limit(500));
+    /// ```
+    #[must_use]
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Method to set retry policy which is used to retry API call failed due
+    /// to transport failure or rate limit note
+    ///
+    /// ```
+    /// use alpha_vantage::api::ApiClient;
+    /// use alpha_vantage::retry::RetryPolicy;
+    /// let api = ApiClient::set_api("some_key", reqwest::Client::new())
+    ///     .retry_policy(RetryPolicy::new(5).retry_transport_failure(false));
+    /// ```
+    #[must_use]
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Method to set cache which stores API output for duration defined by
+    /// cache policy. Output is stored using path of API call without API key
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use alpha_vantage::api::ApiClient;
+    /// use alpha_vantage::cache::{CachePolicy, MemoryCache};
+    /// let policy = CachePolicy::new(Duration::from_secs(60 * 60))
+    ///     .function_ttl("TIME_SERIES_INTRADAY", Duration::from_secs(60));
+    /// let api =
+    ///     ApiClient::set_api("some_key", reqwest::Client::new()).cache(MemoryCache::new(100), policy);
+    /// ```
+    #[must_use]
+    pub fn cache<C>(mut self, cache: C, cache_policy: CachePolicy) -> Self
+    where
+        C: Cache + 'static + Send + Sync,
+    {
+        self.cache = Some((Box::new(cache), cache_policy));
+        self
+    }
+
     // Get json from api endpoint and create struct
     pub(crate) async fn get_json<T>(&self, path: &str) -> Result<T>
     where
         T: DeserializeOwned,
     {
-        let string_output = match &self.provider {
+        decode_json(&self.get_string(path).await?)
+    }
+
+    // Get csv from api endpoint and create vector of struct from its rows
+    pub(crate) async fn get_csv<T>(&self, path: &str) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+    {
+        decode_csv(&self.get_string(path).await?)
+    }
+
+    // Get output from cache or api endpoint retrying as per retry policy
+    async fn get_string(&self, path: &str) -> Result<String> {
+        let cache = self
+            .cache
+            .as_ref()
+            .map(|(cache, cache_policy)| (cache, cache_policy.ttl(path)))
+            .filter(|(_, ttl)| !ttl.is_zero());
+        if let Some((cache, _)) = cache {
+            if let Some(cached_output) = cache.get(path).await {
+                return Ok(cached_output);
+            }
+        }
+
+        let mut attempt = 1;
+        let string_output = loop {
+            let output = self.get_output(path).await;
+            match &self.retry_policy {
+                Some(retry_policy) if retry_policy.should_retry(&output, attempt) => {
+                    retry_policy.wait(attempt).await;
+                    attempt += 1;
+                }
+                _ => break output?,
+            }
+        };
+        if let Some((cache, ttl)) = cache {
+            if !is_message_response(&string_output) {
+                // failing to store output does not make fetched output invalid
+                let _ = cache.set(path, &string_output, ttl).await;
+            }
+        }
+        Ok(string_output)
+    }
+
+    // Get raw output from api endpoint after waiting for rate limiter
+    async fn get_output(&self, path: &str) -> Result<String> {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.wait().await;
+        }
+        match &self.provider {
             Provider::AlphaVantage => {
                 self.client
                     .get_alpha_vantage_provider_output(&format!(
@@ -106,8 +273,7 @@ impl ApiClient {
                     )
                     .await
             }
-        }?;
-        serde_json::from_str(&string_output).map_err(|_| Error::DecodeJsonToStruct)
+        }
     }
 
     /// Crypto method for calling cryptography function with help of
@@ -117,7 +283,8 @@ impl ApiClient {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let crypto = api
     ///         .crypto(alpha_vantage::crypto::CryptoFunction::Daily, "BTC", "CNY")
     ///         .json()
@@ -146,12 +313,29 @@ impl ApiClient {
         CustomBuilder::new(self, function)
     }
 
+    /// Method for returning `CompanyOverviewBuilder` for company overview API
+    /// # Example
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let overview = api.company_overview("IBM").json().await.unwrap();
+    ///     assert_eq!(overview.market_capitalization(), Some(135_402_996_000));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn company_overview<'a>(&'a self, symbol: &'a str) -> CompanyOverviewBuilder<'a> {
+        CompanyOverviewBuilder::new(self, symbol)
+    }
+
     /// Method for returning `EarningBuilder` for earning API
     /// # Example
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let earning = api.earning("IBM").json().await.unwrap();
     ///     let symbol = earning.symbol();
     ///     assert_eq!(symbol, "IBM");
@@ -162,13 +346,113 @@ impl ApiClient {
         EarningBuilder::new(self, symbol)
     }
 
+    /// Method for returning `EarningsCalendarBuilder` for earnings calendar
+    /// API. Earnings of all company are returned if symbol is not provided
+    /// # Example
+    /// ```
+    /// use alpha_vantage::calendar::EarningsCalendarHorizon;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let calendar = api
+    ///         .earnings_calendar(EarningsCalendarHorizon::ThreeMonth, Some("IBM"))
+    ///         .csv()
+    ///         .await
+    ///         .unwrap();
+    ///     let entry = &calendar.entries()[0];
+    ///     assert_eq!(entry.symbol(), "IBM");
+    ///     assert_eq!(entry.report_date(), "2024-01-24");
+    ///     assert_eq!(entry.estimate(), Some(3.77));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn earnings_calendar<'a>(
+        &'a self,
+        horizon: EarningsCalendarHorizon,
+        symbol: Option<&'a str>,
+    ) -> EarningsCalendarBuilder<'a> {
+        EarningsCalendarBuilder::new(self, horizon, symbol)
+    }
+
+    /// Method for returning `IpoCalendarBuilder` for IPO calendar API
+    /// # Example
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let calendar = api.ipo_calendar().csv().await.unwrap();
+    ///     assert_eq!(calendar.entries().len(), 3);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn ipo_calendar(&self) -> IpoCalendarBuilder<'_> {
+        IpoCalendarBuilder::new(self)
+    }
+
+    /// Method for returning `IncomeStatementBuilder` for income statement API
+    /// # Example
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let income_statement = api.income_statement("IBM").json().await.unwrap();
+    ///     let report = &income_statement.quarterly_reports()[0];
+    ///     assert_eq!(report.research_and_development(), Some(1_654_000_000));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn income_statement<'a>(&'a self, symbol: &'a str) -> IncomeStatementBuilder<'a> {
+        IncomeStatementBuilder::new(self, symbol)
+    }
+
+    /// Method for returning `BalanceSheetBuilder` for balance sheet API
+    /// # Example
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let balance_sheet = api.balance_sheet("IBM").json().await.unwrap();
+    ///     let report = &balance_sheet.annual_reports()[0];
+    ///     assert_eq!(report.total_assets(), Some(127_243_000_000));
+    ///     assert_eq!(report.deferred_revenue(), None);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn balance_sheet<'a>(&'a self, symbol: &'a str) -> BalanceSheetBuilder<'a> {
+        BalanceSheetBuilder::new(self, symbol)
+    }
+
+    /// Method for returning `CashFlowBuilder` for cash flow API
+    /// # Example
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let cash_flow = api.cash_flow("IBM").json().await.unwrap();
+    ///     let report = &cash_flow.annual_reports()[0];
+    ///     assert_eq!(report.operating_cashflow(), Some(10_435_000_000));
+    ///     assert_eq!(report.change_in_exchange_rate(), Some(-303_000_000));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn cash_flow<'a>(&'a self, symbol: &'a str) -> CashFlowBuilder<'a> {
+        CashFlowBuilder::new(self, symbol)
+    }
+
     /// Method for economic indicator builder
     ///
     /// # Example
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let economic = api
     ///         .economic_indicator("REAL_GDP_PER_CAPITA")
     ///         .json()
@@ -182,6 +466,34 @@ impl ApiClient {
         EconomicIndicatorBuilder::new(self, function)
     }
 
+    /// Method for economic indicator builder using typed function. Interval
+    /// and maturity are checked against values supported by function before
+    /// request is sent
+    ///
+    /// # Example
+    /// ```
+    /// use alpha_vantage::economic_indicator::{EconomicIndicatorFunction, EconomicIndicatorMaturity};
+    /// use alpha_vantage::error::Error;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let error = api
+    ///         .economic(EconomicIndicatorFunction::Cpi)
+    ///         .maturity(EconomicIndicatorMaturity::TenYear)
+    ///         .json()
+    ///         .await
+    ///         .err()
+    ///         .unwrap();
+    ///     assert!(matches!(error, Error::InvalidParameter(_)));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn economic(&self, function: EconomicIndicatorFunction) -> EconomicIndicatorBuilder<'_> {
+        EconomicIndicatorBuilder::with_function(self, function)
+    }
+
     /// Method for creating `ExchangeBuilder` for exchanging currency value from
     /// one currency to another currency.
     ///
@@ -189,7 +501,8 @@ impl ApiClient {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let exchange = api.exchange("BTC", "CNY").json().await.unwrap();
     ///     assert_eq!(exchange.name_from(), "Bitcoin");
     ///     assert_eq!(exchange.code_from(), "BTC");
@@ -212,7 +525,8 @@ impl ApiClient {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let forex = api
     ///         .forex(alpha_vantage::forex::ForexFunction::Weekly, "EUR", "USD")
     ///         .json()
@@ -233,12 +547,61 @@ impl ApiClient {
         ForexBuilder::new(self, function, from_symbol, to_symbol)
     }
 
+    /// Method for returning `ListingStatusBuilder` for listing and delisting
+    /// status API
+    /// # Example
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let listing_status = api.listing_status().csv().await.unwrap();
+    ///     assert!(listing_status.is_listed("IBM"));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn listing_status(&self) -> ListingStatusBuilder<'_> {
+        ListingStatusBuilder::new(self)
+    }
+
+    /// Method for returning `NewsSentimentBuilder` for news and sentiment API
+    /// # Example
+    /// ```
+    /// use alpha_vantage::news::{NewsSort, NewsTopic};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let news = api
+    ///         .news_sentiment()
+    ///         .tickers(&["IBM"])
+    ///         .topics(&[NewsTopic::Technology, NewsTopic::Earnings])
+    ///         .time_from("20231017T0000")
+    ///         .sort(NewsSort::Latest)
+    ///         .limit(50)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     assert_eq!(news.items(), 2);
+    ///     assert_eq!(
+    ///         news.feed()[0].title(),
+    ///         "IBM Expands Watsonx Platform With New Generative AI Models"
+    ///     );
+    /// }
+    /// ```
+    #[must_use]
+    pub fn news_sentiment(&self) -> NewsSentimentBuilder<'_> {
+        NewsSentimentBuilder::new(self)
+    }
+
     /// Method for creating `QuoteBuilder` from `APIClient`
     /// # Example
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let quote = api.quote("MSFT").json().await.unwrap();
     ///     let symbol = quote.symbol();
     ///     assert_eq!(symbol, "MSFT");
@@ -255,7 +618,8 @@ impl ApiClient {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let search = api.search("BA").json().await.unwrap();
     ///     let first_search_match = &search.matches()[0];
     ///     assert_eq!(first_search_match.symbol(), "BA");
@@ -277,7 +641,8 @@ impl ApiClient {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let stock = api
     ///         .stock_time(alpha_vantage::stock_time::StockFunction::Weekly, "MSFT")
     ///         .json()
@@ -302,7 +667,8 @@ impl ApiClient {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let technical = api
     ///         .technical_indicator(
     ///             "MAMA",
@@ -325,6 +691,43 @@ impl ApiClient {
     ) -> TechnicalIndicatorBuilder<'a> {
         TechnicalIndicatorBuilder::new(self, function, symbol, interval)
     }
+
+    /// Method for technical indicator builder using typed function. Parameter
+    /// of function is validated before request is sent
+    ///
+    /// # Example
+    /// ```
+    /// use alpha_vantage::error::Error;
+    /// use alpha_vantage::technical_indicator::{
+    ///     PeriodSeriesParams, SeriesType, TechnicalIndicatorFunction, TechnicalIndicatorInterval,
+    /// };
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::new();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let error = api
+    ///         .indicator(
+    ///             TechnicalIndicatorFunction::Sma(PeriodSeriesParams::new(0, SeriesType::Close)),
+    ///             "IBM",
+    ///             TechnicalIndicatorInterval::Daily,
+    ///         )
+    ///         .json()
+    ///         .await
+    ///         .err()
+    ///         .unwrap();
+    ///     assert!(matches!(error, Error::InvalidParameter(_)));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn indicator<'a>(
+        &'a self,
+        function: TechnicalIndicatorFunction,
+        symbol: &'a str,
+        interval: TechnicalIndicatorInterval,
+    ) -> TechnicalIndicatorBuilder<'a> {
+        TechnicalIndicatorBuilder::with_function(self, function, symbol, interval)
+    }
 }
 
 /// Enum for declaring output size of API call
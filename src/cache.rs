@@ -0,0 +1,268 @@
+//! Module for caching API response
+//!
+//! Daily, weekly and monthly series change at most once per day so
+//! downloading them again on every call wastes both quota and bandwidth.
+//! `Cache` trait can be implemented to store raw API output and attached to
+//! `ApiClient` along with `CachePolicy` which defines how long response of each
+//! function is valid. Crate provides in memory LRU cache `MemoryCache` and on
+//! disk cache `DiskCache`.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use lru::LruCache;
+
+#[async_trait]
+/// Trait which can be implemented for storing raw API output. Key is path of
+/// API call without API key
+pub trait Cache {
+    /// Return cached output for key. Returns None if key is not present or
+    /// cached value is expired
+    async fn get(&self, key: &str) -> Option<String>;
+
+    /// Store output for key which is valid for ttl duration
+    ///
+    /// # Errors
+    /// Returns error if output cannot be stored. `ApiClient` ignores this
+    /// error as output fetched from API is still valid
+    async fn set(&self, key: &str, value: &str, ttl: Duration) -> io::Result<()>;
+}
+
+/// Policy which defines time to live of cached response for each function
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use alpha_vantage::cache::CachePolicy;
+/// let policy = CachePolicy::new(Duration::from_secs(60 * 60 * 24))
+///     .function_ttl("TIME_SERIES_INTRADAY", Duration::from_secs(60))
+///     .function_ttl("FX_INTRADAY", Duration::from_secs(60));
+/// assert_eq!(
+///     policy.ttl("query?function=TIME_SERIES_INTRADAY&symbol=IBM&interval=5min"),
+///     Duration::from_secs(60)
+/// );
+/// assert_eq!(
+///     policy.ttl("query?function=TIME_SERIES_DAILY&symbol=IBM"),
+///     Duration::from_secs(60 * 60 * 24)
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct CachePolicy {
+    default_ttl: Duration,
+    function_ttl: HashMap<String, Duration>,
+}
+
+impl CachePolicy {
+    /// Create new cache policy with default time to live used for all
+    /// function
+    #[must_use]
+    pub fn new(default_ttl: Duration) -> Self {
+        Self {
+            default_ttl,
+            function_ttl: HashMap::new(),
+        }
+    }
+
+    /// Set time to live for a specific function. Use zero duration to disable
+    /// caching of function
+    #[must_use]
+    pub fn function_ttl<S>(mut self, function: S, ttl: Duration) -> Self
+    where
+        S: Into<String>,
+    {
+        self.function_ttl.insert(function.into(), ttl);
+        self
+    }
+
+    /// Return time to live for a path
+    #[must_use]
+    pub fn ttl(&self, path: &str) -> Duration {
+        path_function(path)
+            .and_then(|function| self.function_ttl.get(function))
+            .copied()
+            .unwrap_or(self.default_ttl)
+    }
+}
+
+/// Return function name present in path
+fn path_function(path: &str) -> Option<&str> {
+    let (_, query) = path.split_once('?')?;
+    query
+        .split('&')
+        .find_map(|param| param.strip_prefix("function="))
+}
+
+/// In memory least recently used cache
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use alpha_vantage::cache::{Cache, MemoryCache};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let cache = MemoryCache::new(2);
+///     cache
+///         .set("first", "1", Duration::from_secs(60))
+///         .await
+///         .unwrap();
+///     cache
+///         .set("second", "2", Duration::from_secs(60))
+///         .await
+///         .unwrap();
+///     cache
+///         .set("third", "3", Duration::from_secs(60))
+///         .await
+///         .unwrap();
+///     assert!(cache.get("first").await.is_none());
+///     assert_eq!(cache.get("third").await.unwrap(), "3");
+/// }
+/// ```
+pub struct MemoryCache {
+    entries: Mutex<LruCache<String, (Instant, String)>>,
+}
+
+impl MemoryCache {
+    /// Create new memory cache which stores at most capacity number of
+    /// response
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: Mutex::new(LruCache::new(
+                NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN),
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl Cache for MemoryCache {
+    async fn get(&self, key: &str) -> Option<String> {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        match entries.get(key) {
+            Some((expire_at, value)) if *expire_at > Instant::now() => Some(value.clone()),
+            Some(_) => {
+                entries.pop(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    async fn set(&self, key: &str, value: &str, ttl: Duration) -> io::Result<()> {
+        if let Some(expire_at) = Instant::now().checked_add(ttl) {
+            self.entries
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .put(key.to_string(), (expire_at, value.to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// On disk cache which stores each response as a file inside a directory
+///
+/// Each file contains expiry time and key in first two lines followed by
+/// response. Response is first written to a temporary file which is then
+/// renamed so a partially written file is never read.
+///
+/// Crate does not depend on any async runtime so files are read and written
+/// using blocking `std::fs` calls inside `get` and `set`. Cached responses are
+/// small so call returns quickly but it still blocks executor thread while
+/// file is accessed.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use alpha_vantage::cache::{Cache, DiskCache};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let directory = std::env::temp_dir().join("alpha_vantage_disk_cache_doc");
+///     let cache = DiskCache::new(&directory);
+///     cache
+///         .set("query?function=OVERVIEW", "{}", Duration::from_secs(60))
+///         .await
+///         .unwrap();
+///     assert_eq!(cache.get("query?function=OVERVIEW").await.unwrap(), "{}");
+///     assert!(cache.get("query?function=EARNINGS").await.is_none());
+///     std::fs::remove_dir_all(directory).unwrap();
+/// }
+/// ```
+pub struct DiskCache {
+    directory: PathBuf,
+}
+
+impl DiskCache {
+    /// Create new disk cache which stores response inside directory. Directory
+    /// is created if it does not exists
+    #[must_use]
+    pub fn new<P>(directory: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    /// Return file path used for storing key
+    fn file_path(&self, key: &str) -> PathBuf {
+        // FNV-1a hash is used as it is stable across rust version
+        let hash = key.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+        self.directory.join(format!("{hash:016x}.cache"))
+    }
+}
+
+/// Return current time as seconds since unix epoch
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+#[async_trait]
+impl Cache for DiskCache {
+    async fn get(&self, key: &str) -> Option<String> {
+        let path = self.file_path(key);
+        let content = fs::read_to_string(&path).ok()?;
+        let mut parts = content.splitn(3, '\n');
+        let expire_at = parts.next()?.parse::<u64>().ok()?;
+        let stored_key = parts.next()?;
+        let value = parts.next()?;
+        if stored_key != key {
+            return None;
+        }
+        if expire_at <= unix_now() {
+            let _ = fs::remove_file(path);
+            return None;
+        }
+        Some(value.to_string())
+    }
+
+    async fn set(&self, key: &str, value: &str, ttl: Duration) -> io::Result<()> {
+        fs::create_dir_all(&self.directory)?;
+        // expiry is stored in whole seconds so sub second ttl is rounded up
+        // instead of expiring immediately
+        let ttl_secs = ttl.as_secs() + u64::from(ttl.subsec_nanos() > 0);
+        let expire_at = unix_now().saturating_add(ttl_secs);
+        let path = self.file_path(key);
+        // temporary file name is random so concurrent set of same key do not
+        // write into same temporary file
+        let temp_path = path.with_extension(format!("{:016x}.tmp", fastrand::u64(..)));
+        let result = fs::write(&temp_path, format!("{expire_at}\n{key}\n{value}"))
+            .and_then(|()| fs::rename(&temp_path, path));
+        if result.is_err() {
+            let _ = fs::remove_file(&temp_path);
+        }
+        result
+    }
+}
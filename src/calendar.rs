@@ -0,0 +1,292 @@
+//! Module for returning earnings and IPO calendar
+//!
+//! APIs under this section return list of company earnings expected in the
+//! next 3, 6, or 12 months and list of IPOs expected in the next 3 months.
+//! Both APIs return CSV instead of JSON.
+//!
+//! You can read about [Earnings Calendar][earnings_calendar] and [IPO
+//! Calendar][ipo_calendar] API and what it returns on alphavantage
+//! documentation
+//!
+//! [earnings_calendar]: https://www.alphavantage.co/documentation/#earnings-calendar
+//! [ipo_calendar]: https://www.alphavantage.co/documentation/#ipo-calendar
+
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::ApiClient;
+use crate::deserialize::from_missing_str;
+use crate::error::Result;
+
+/// Struct to store single upcoming earning of company
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct EarningsCalendarEntry {
+    symbol: String,
+    name: String,
+    #[serde(rename(deserialize = "reportDate"), alias = "report_date")]
+    report_date: String,
+    #[serde(rename(deserialize = "fiscalDateEnding"), alias = "fiscal_date_ending")]
+    fiscal_date_ending: String,
+    #[serde(deserialize_with = "from_missing_str")]
+    estimate: Option<f64>,
+    currency: String,
+}
+
+impl EarningsCalendarEntry {
+    /// Return symbol of company
+    #[must_use]
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Return name of company
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return date when earning is expected to be reported
+    #[must_use]
+    pub fn report_date(&self) -> &str {
+        &self.report_date
+    }
+
+    /// Return fiscal date ending of earning
+    #[must_use]
+    pub fn fiscal_date_ending(&self) -> &str {
+        &self.fiscal_date_ending
+    }
+
+    /// Return estimated eps. Return None if estimate is not available
+    #[must_use]
+    pub fn estimate(&self) -> Option<f64> {
+        self.estimate
+    }
+
+    /// Return currency of estimate
+    #[must_use]
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+}
+
+/// Struct to store earnings calendar
+///
+/// Serialized as object with `entries` containing list of object with
+/// `symbol`, `name`, `report_date`, `fiscal_date_ending`, `estimate` and
+/// `currency`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EarningsCalendar {
+    entries: Vec<EarningsCalendarEntry>,
+}
+
+impl EarningsCalendar {
+    /// Return upcoming earnings
+    ///
+    /// # Example
+    /// ```
+    /// use alpha_vantage::calendar::EarningsCalendarHorizon;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let calendar = api
+    ///         .earnings_calendar(EarningsCalendarHorizon::ThreeMonth, None)
+    ///         .csv()
+    ///         .await
+    ///         .unwrap();
+    ///     let entries = calendar.entries();
+    ///     assert_eq!(entries.len(), 4);
+    ///     assert_eq!(entries[1].estimate(), Some(-0.53));
+    ///     assert_eq!(entries[2].estimate(), None);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn entries(&self) -> &Vec<EarningsCalendarEntry> {
+        &self.entries
+    }
+}
+
+impl From<Vec<EarningsCalendarEntry>> for EarningsCalendar {
+    fn from(entries: Vec<EarningsCalendarEntry>) -> Self {
+        Self { entries }
+    }
+}
+
+/// Enum for declaring horizon of earnings calendar
+#[derive(Clone, Copy)]
+pub enum EarningsCalendarHorizon {
+    /// earnings expected in next 3 months
+    ThreeMonth,
+    /// earnings expected in next 6 months
+    SixMonth,
+    /// earnings expected in next 12 months
+    TwelveMonth,
+}
+
+/// Builder to help create `EarningsCalendar`
+pub struct EarningsCalendarBuilder<'a> {
+    api_client: &'a ApiClient,
+    horizon: EarningsCalendarHorizon,
+    symbol: Option<&'a str>,
+}
+
+impl<'a> EarningsCalendarBuilder<'a> {
+    crate::csv_data_struct!(EarningsCalendar, EarningsCalendarEntry);
+
+    /// Create new `EarningsCalendarBuilder` with help of `APIClient`
+    #[must_use]
+    pub fn new(
+        api_client: &'a ApiClient,
+        horizon: EarningsCalendarHorizon,
+        symbol: Option<&'a str>,
+    ) -> Self {
+        Self {
+            api_client,
+            horizon,
+            symbol,
+        }
+    }
+
+    fn create_url(&self) -> String {
+        let horizon = match self.horizon {
+            EarningsCalendarHorizon::ThreeMonth => "3month",
+            EarningsCalendarHorizon::SixMonth => "6month",
+            EarningsCalendarHorizon::TwelveMonth => "12month",
+        };
+        let mut created_link = format!("query?function=EARNINGS_CALENDAR&horizon={horizon}");
+        if let Some(symbol) = self.symbol {
+            let _ = write!(created_link, "&symbol={symbol}");
+        }
+        created_link
+    }
+}
+
+/// Struct to store single upcoming IPO
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct IpoCalendarEntry {
+    symbol: String,
+    name: String,
+    #[serde(rename(deserialize = "ipoDate"), alias = "ipo_date")]
+    ipo_date: String,
+    #[serde(
+        rename(deserialize = "priceRangeLow"),
+        alias = "price_range_low",
+        deserialize_with = "from_missing_str"
+    )]
+    price_range_low: Option<f64>,
+    #[serde(
+        rename(deserialize = "priceRangeHigh"),
+        alias = "price_range_high",
+        deserialize_with = "from_missing_str"
+    )]
+    price_range_high: Option<f64>,
+    currency: String,
+    exchange: String,
+}
+
+impl IpoCalendarEntry {
+    /// Return symbol of company
+    #[must_use]
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Return name of company
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return expected IPO date
+    #[must_use]
+    pub fn ipo_date(&self) -> &str {
+        &self.ipo_date
+    }
+
+    /// Return low value of price range. Return None if value is not available
+    #[must_use]
+    pub fn price_range_low(&self) -> Option<f64> {
+        self.price_range_low
+    }
+
+    /// Return high value of price range. Return None if value is not available
+    #[must_use]
+    pub fn price_range_high(&self) -> Option<f64> {
+        self.price_range_high
+    }
+
+    /// Return currency of price range
+    #[must_use]
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Return exchange where company is going to be listed
+    #[must_use]
+    pub fn exchange(&self) -> &str {
+        &self.exchange
+    }
+}
+
+/// Struct to store IPO calendar
+///
+/// Serialized as object with `entries` containing list of object with
+/// `symbol`, `name`, `ipo_date`, `price_range_low`, `price_range_high`,
+/// `currency` and `exchange`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IpoCalendar {
+    entries: Vec<IpoCalendarEntry>,
+}
+
+impl IpoCalendar {
+    /// Return upcoming IPOs
+    ///
+    /// # Example
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let calendar = api.ipo_calendar().csv().await.unwrap();
+    ///     let entry = &calendar.entries()[0];
+    ///     assert_eq!(entry.symbol(), "BIRK");
+    ///     assert_eq!(entry.ipo_date(), "2023-10-11");
+    ///     assert_eq!(entry.price_range_low(), Some(44.0));
+    ///     assert_eq!(entry.price_range_high(), Some(49.0));
+    ///     assert_eq!(entry.exchange(), "NYSE");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn entries(&self) -> &Vec<IpoCalendarEntry> {
+        &self.entries
+    }
+}
+
+impl From<Vec<IpoCalendarEntry>> for IpoCalendar {
+    fn from(entries: Vec<IpoCalendarEntry>) -> Self {
+        Self { entries }
+    }
+}
+
+/// Builder to help create `IpoCalendar`
+pub struct IpoCalendarBuilder<'a> {
+    api_client: &'a ApiClient,
+}
+
+impl<'a> IpoCalendarBuilder<'a> {
+    crate::csv_data_struct!(IpoCalendar, IpoCalendarEntry);
+
+    /// Create new `IpoCalendarBuilder` with help of `APIClient`
+    #[must_use]
+    pub fn new(api_client: &'a ApiClient) -> Self {
+        Self { api_client }
+    }
+
+    #[allow(clippy::unused_self)]
+    fn create_url(&self) -> String {
+        "query?function=IPO_CALENDAR".to_string()
+    }
+}
@@ -0,0 +1,61 @@
+//! Module which contains common trait for open, high, low and close data
+//!
+//! Stock time series, forex and crypto data all store price as candle.
+//! `Candle` trait can be used for writing code which handles data of any asset
+//! class uniformly.
+
+/// Trait implemented by data of stock time series, forex and crypto
+///
+/// `FindData` is implemented for vector of any candle and uses binary search
+/// on `time`, so vector needs to be sorted by `time` in ascending order. Data
+/// returned by API is already sorted. Custom implementation needs to return
+/// time whose string order matches chronological order such as `YYYY-MM-DD`
+/// or `YYYY-MM-DD HH:MM:SS`
+///
+/// ```
+/// use alpha_vantage::candle::Candle;
+///
+/// fn average_range<T: Candle>(data: &[T]) -> f64 {
+///     let total: f64 = data.iter().map(|candle| candle.high() - candle.low()).sum();
+///     total / data.len() as f64
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+///     let api = alpha_vantage::set_api("demo", client);
+///     let stock = api
+///         .stock_time(alpha_vantage::stock_time::StockFunction::Weekly, "MSFT")
+///         .json()
+///         .await
+///         .unwrap();
+///     let forex = api
+///         .forex(alpha_vantage::forex::ForexFunction::Weekly, "EUR", "USD")
+///         .json()
+///         .await
+///         .unwrap();
+///     assert!(average_range(stock.data()) > 0.0);
+///     assert!(average_range(forex.data()) > 0.0);
+///     assert!(Candle::volume(&forex.data()[0]).is_none());
+/// }
+/// ```
+pub trait Candle {
+    /// Return time of candle. String order of time needs to match
+    /// chronological order
+    fn time(&self) -> &str;
+
+    /// Return open value
+    fn open(&self) -> f64;
+
+    /// Return high value
+    fn high(&self) -> f64;
+
+    /// Return low value
+    fn low(&self) -> f64;
+
+    /// Return close value
+    fn close(&self) -> f64;
+
+    /// Return volume. Returns None if data does not contain volume
+    fn volume(&self) -> Option<f64>;
+}
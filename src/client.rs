@@ -1,48 +1,155 @@
 use async_trait::async_trait;
 
-use crate::error::{Error, Result};
+#[cfg(feature = "reqwest-client")]
+use crate::error::{Error, HttpErrorKind};
+use crate::error::Result;
 
 #[async_trait]
 /// Trait which can be implemented for all common library client for getting
 /// output from server
-/// reqwest is client which is supported with feature flag. If
+/// `reqwest` is client which is supported with feature flag. If
 /// you prefer alternate http client you can add support by implementing
 /// `HttpClient` trait for client.
-/// Some example of other client which can be used are surf and isahc client
+/// Some example of other client which can be used are surf and isahc client.
+/// Implementation should return `Error::HttpRequestFailed` when server returns
+/// non success status code instead of returning body of error page
 pub trait HttpClient {
-    /// AlphaVantage provider output function which provides one field path
+    /// `AlphaVantage` provider output function which provides one field path
     /// where get GET request needs to be performed
     async fn get_alpha_vantage_provider_output(&self, path: &str) -> Result<String>;
 
-    /// RapidAPI provider function which provides two field path and api_key.
-    /// Path needs to be set along with header x-rapidapi-host as
+    /// `RapidAPI` provider function which provides two field path and
+    /// `api_key`. Path needs to be set along with header x-rapidapi-host as
     /// alpha-vantage.p.rapidapi.com and header x-rapidapi-key same as
-    /// api_key field
+    /// `api_key` field
     async fn get_rapid_api_provider_output(&self, path: &str, api_key: &str) -> Result<String>;
 }
 
+/// Convert `reqwest` error to `Error::HttpRequestFailed`
+#[cfg(feature = "reqwest-client")]
+fn reqwest_error(error: reqwest::Error) -> Error {
+    let kind = if error.is_timeout() {
+        HttpErrorKind::Timeout
+    } else if error.is_connect() {
+        HttpErrorKind::Connect
+    } else if error.is_status() {
+        HttpErrorKind::Status
+    } else if error.is_body() || error.is_decode() {
+        HttpErrorKind::Body
+    } else if error.is_request() || error.is_builder() || error.is_redirect() {
+        HttpErrorKind::Request
+    } else {
+        HttpErrorKind::Other
+    };
+    Error::HttpRequestFailed {
+        kind,
+        status: error.status().map(|status| status.as_u16()),
+        body: None,
+        source: Some(Box::new(error)),
+    }
+}
+
+/// Create `Error::HttpRequestFailed` for non success status code
+#[cfg(feature = "reqwest-client")]
+fn status_error(status: reqwest::StatusCode, body: Option<String>) -> Error {
+    Error::HttpRequestFailed {
+        kind: HttpErrorKind::Status,
+        status: Some(status.as_u16()),
+        body,
+        source: None,
+    }
+}
+
+#[cfg(feature = "reqwest-client")]
+async fn reqwest_output(request: reqwest::RequestBuilder) -> Result<String> {
+    let response = request.send().await.map_err(reqwest_error)?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(status_error(status, response.text().await.ok()));
+    }
+    response.text().await.map_err(reqwest_error)
+}
+
 #[cfg(feature = "reqwest-client")]
 #[async_trait]
 impl HttpClient for reqwest::Client {
     async fn get_alpha_vantage_provider_output(&self, path: &str) -> Result<String> {
-        self.get(path)
-            .send()
-            .await
-            .map_err(|_| Error::GetRequestFailed)?
-            .text()
-            .await
-            .map_err(|_| Error::GetRequestFailed)
+        reqwest_output(self.get(path)).await
+    }
+
+    async fn get_rapid_api_provider_output(&self, path: &str, api_key: &str) -> Result<String> {
+        reqwest_output(
+            self.get(path)
+                .header("x-rapidapi-host", "alpha-vantage.p.rapidapi.com")
+                .header("x-rapidapi-key", api_key),
+        )
+        .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+/// Synchronous counterpart of `HttpClient` trait which can be implemented for
+/// blocking client. `reqwest::blocking::Client` is supported when both
+/// `blocking` and `reqwest-client` feature are enabled
+pub trait BlockingHttpClient {
+    /// `AlphaVantage` provider output function which provides one field path
+    /// where get GET request needs to be performed
+    ///
+    /// # Errors
+    /// Raise error if output cannot be obtained from server
+    fn get_alpha_vantage_provider_output(&self, path: &str) -> Result<String>;
+
+    /// `RapidAPI` provider function which provides two field path and
+    /// `api_key`. Path needs to be set along with header x-rapidapi-host as
+    /// alpha-vantage.p.rapidapi.com and header x-rapidapi-key same as
+    /// `api_key` field
+    ///
+    /// # Errors
+    /// Raise error if output cannot be obtained from server
+    fn get_rapid_api_provider_output(&self, path: &str, api_key: &str) -> Result<String>;
+}
+
+#[cfg(all(feature = "blocking", feature = "reqwest-client"))]
+fn reqwest_blocking_output(request: reqwest::blocking::RequestBuilder) -> Result<String> {
+    let response = request.send().map_err(reqwest_error)?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(status_error(status, response.text().ok()));
+    }
+    response.text().map_err(reqwest_error)
+}
+
+#[cfg(all(feature = "blocking", feature = "reqwest-client"))]
+impl BlockingHttpClient for reqwest::blocking::Client {
+    fn get_alpha_vantage_provider_output(&self, path: &str) -> Result<String> {
+        reqwest_blocking_output(self.get(path))
+    }
+
+    fn get_rapid_api_provider_output(&self, path: &str, api_key: &str) -> Result<String> {
+        reqwest_blocking_output(
+            self.get(path)
+                .header("x-rapidapi-host", "alpha-vantage.p.rapidapi.com")
+                .header("x-rapidapi-key", api_key),
+        )
+    }
+}
+
+#[cfg(feature = "blocking")]
+/// Wrapper which allows blocking client to be used as `HttpClient`. Output is
+/// obtained by blocking current thread
+pub(crate) struct BlockingWrapper<T>(pub(crate) T);
+
+#[cfg(feature = "blocking")]
+#[async_trait]
+impl<T> HttpClient for BlockingWrapper<T>
+where
+    T: BlockingHttpClient + Send + Sync,
+{
+    async fn get_alpha_vantage_provider_output(&self, path: &str) -> Result<String> {
+        self.0.get_alpha_vantage_provider_output(path)
     }
 
     async fn get_rapid_api_provider_output(&self, path: &str, api_key: &str) -> Result<String> {
-        self.get(path)
-            .header("x-rapidapi-host", "alpha-vantage.p.rapidapi.com")
-            .header("x-rapidapi-key", api_key)
-            .send()
-            .await
-            .map_err(|_| Error::GetRequestFailed)?
-            .text()
-            .await
-            .map_err(|_| Error::GetRequestFailed)
+        self.0.get_rapid_api_provider_output(path, api_key)
     }
 }
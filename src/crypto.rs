@@ -8,50 +8,60 @@
 //!
 //! [crypto_currency]: https://www.alphavantage.co/documentation/#digital-currency
 
-use std::cmp;
 use std::collections::HashMap;
 use std::str::FromStr;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::api::ApiClient;
-use crate::deserialize::from_str;
+use crate::candle::Candle;
+use crate::deserialize::{from_str, Price};
+#[cfg(feature = "chrono")]
+use crate::deserialize::{parse_datetime, parse_time_zone};
 use crate::error::{detect_common_helper_error, Error, Result};
-use crate::vec_trait::FindData;
 
 /// Store Meta Data Information
-#[derive(Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 struct MetaData {
-    #[serde(rename = "1. Information")]
+    #[serde(rename(deserialize = "1. Information"), alias = "information")]
     information: String,
-    #[serde(rename = "2. Digital Currency Code")]
+    #[serde(
+        rename(deserialize = "2. Digital Currency Code"),
+        alias = "digital_code"
+    )]
     digital_code: String,
-    #[serde(rename = "3. Digital Currency Name")]
+    #[serde(
+        rename(deserialize = "3. Digital Currency Name"),
+        alias = "digital_name"
+    )]
     digital_name: String,
-    #[serde(rename = "4. Market Code")]
+    #[serde(rename(deserialize = "4. Market Code"), alias = "market_code")]
     market_code: String,
-    #[serde(rename = "5. Market Name")]
+    #[serde(rename(deserialize = "5. Market Name"), alias = "market_name")]
     market_name: String,
-    #[serde(rename = "6. Last Refreshed")]
+    #[serde(rename(deserialize = "6. Last Refreshed"), alias = "last_refreshed")]
     last_refreshed: String,
-    #[serde(rename = "7. Time Zone")]
+    #[serde(rename(deserialize = "7. Time Zone"), alias = "time_zone")]
     time_zone: String,
 }
 
 /// Struct which stores Crypto data
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct Data {
     time: String,
-    market_open: f64,
-    usd_open: f64,
-    market_high: f64,
-    usd_high: f64,
-    market_low: f64,
-    usd_low: f64,
-    market_close: f64,
-    usd_close: f64,
+    market_open: Price,
+    usd_open: Price,
+    market_high: Price,
+    usd_high: Price,
+    market_low: Price,
+    usd_low: Price,
+    market_close: Price,
+    usd_close: Price,
     volume: f64,
     market_cap: f64,
+    #[cfg(feature = "chrono")]
+    #[serde(skip)]
+    time_zone: String,
 }
 
 impl Data {
@@ -61,52 +71,125 @@ impl Data {
         &self.time
     }
 
+    /// Return time as date time in time zone of meta data
+    ///
+    /// # Errors
+    /// Raise error if time or time zone of meta data cannot be parsed
+    #[cfg(feature = "chrono")]
+    pub fn datetime(&self) -> Result<chrono::DateTime<chrono_tz::Tz>> {
+        parse_datetime(&self.time, parse_time_zone(&self.time_zone)?)
+    }
+
     /// Return market open value
     #[must_use]
     pub fn market_open(&self) -> f64 {
-        self.market_open
+        self.market_open.value
+    }
+
+    /// Return market open value as exact decimal. Return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn market_open_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.market_open.decimal
     }
 
     /// Return usd open value
     #[must_use]
     pub fn usd_open(&self) -> f64 {
-        self.usd_open
+        self.usd_open.value
+    }
+
+    /// Return usd open value as exact decimal. Return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn usd_open_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.usd_open.decimal
     }
 
     /// Return market high value
     #[must_use]
     pub fn market_high(&self) -> f64 {
-        self.market_high
+        self.market_high.value
+    }
+
+    /// Return market high value as exact decimal. Return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn market_high_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.market_high.decimal
     }
 
     /// Return usd high value
     #[must_use]
     pub fn usd_high(&self) -> f64 {
-        self.usd_high
+        self.usd_high.value
+    }
+
+    /// Return usd high value as exact decimal. Return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn usd_high_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.usd_high.decimal
     }
 
     /// Return market low value
     #[must_use]
     pub fn market_low(&self) -> f64 {
-        self.market_low
+        self.market_low.value
+    }
+
+    /// Return market low value as exact decimal. Return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn market_low_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.market_low.decimal
     }
 
     /// Return usd low value
     #[must_use]
     pub fn usd_low(&self) -> f64 {
-        self.usd_low
+        self.usd_low.value
+    }
+
+    /// Return usd low value as exact decimal. Return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn usd_low_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.usd_low.decimal
     }
 
     /// Return market close value
     #[must_use]
     pub fn market_close(&self) -> f64 {
-        self.market_close
+        self.market_close.value
+    }
+
+    /// Return market close value as exact decimal. Return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn market_close_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.market_close.decimal
     }
 
     /// Return usd close value
     #[must_use]
     pub fn usd_close(&self) -> f64 {
-        self.usd_close
+        self.usd_close.value
+    }
+
+    /// Return usd close value as exact decimal. Return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn usd_close_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.usd_close.decimal
     }
 
     /// Return volume
@@ -123,19 +206,81 @@ impl Data {
 }
 
 /// Struct which holds Crypto currency information
-#[derive(Default)]
+///
+/// Serialized as object with `meta_data` containing `information`,
+/// `digital_code`, `digital_name`, `market_code`, `market_name`,
+/// `last_refreshed` and `time_zone` and `data` containing list of object with
+/// `time`, `market_open`, `usd_open`, `market_high`, `usd_high`, `market_low`,
+/// `usd_low`, `market_close`, `usd_close`, `volume` and `market_cap`
+///
+/// Decoding returns `Error::AlphaVantageInvalidData` if market value cannot be
+/// parsed
+///
+/// ```
+/// use alpha_vantage::error::Error;
+/// use alpha_vantage::mock::MockClient;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = MockClient::new().response(
+///         "function=DIGITAL_CURRENCY_DAILY",
+///         r#"{"Meta Data": {"1. Information": "Daily", "2. Digital Currency Code": "BTC",
+///             "3. Digital Currency Name": "Bitcoin", "4. Market Code": "CNY",
+///             "5. Market Name": "Chinese Yuan", "6. Last Refreshed": "2024-01-02",
+///             "7. Time Zone": "UTC"},
+///             "Time Series (Digital Currency Daily)": {"2024-01-02": {
+///             "1a. open (CNY)": "abc", "1b. open (USD)": "1", "2b. high (USD)": "1",
+///             "3b. low (USD)": "1", "4b. close (USD)": "1", "5. volume": "1",
+///             "6. market cap (USD)": "1"}}}"#,
+///     );
+///     let api = alpha_vantage::set_api("demo", client);
+///     let error = api
+///         .crypto(alpha_vantage::crypto::CryptoFunction::Daily, "BTC", "CNY")
+///         .json()
+///         .await
+///         .err()
+///         .unwrap();
+///     assert!(matches!(error, Error::AlphaVantageInvalidData(_)));
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(from = "CryptoRecord")]
 pub struct Crypto {
     meta_data: MetaData,
     data: Vec<Data>,
 }
 
+/// Normalized representation of `Crypto` used for sorting data and restoring
+/// time zone of data while deserializing
+#[derive(Deserialize)]
+struct CryptoRecord {
+    meta_data: MetaData,
+    data: Vec<Data>,
+}
+
+impl From<CryptoRecord> for Crypto {
+    fn from(record: CryptoRecord) -> Self {
+        let mut data = record.data;
+        #[cfg(feature = "chrono")]
+        for value in &mut data {
+            value.time_zone.clone_from(&record.meta_data.time_zone);
+        }
+        data.sort_by(|first, second| first.time.cmp(&second.time));
+        Self {
+            meta_data: record.meta_data,
+            data,
+        }
+    }
+}
+
 impl Crypto {
     /// Return meta data information
     ///
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let crypto = api
     ///         .crypto(alpha_vantage::crypto::CryptoFunction::Daily, "BTC", "CNY")
     ///         .json()
@@ -155,7 +300,8 @@ impl Crypto {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let crypto = api
     ///         .crypto(alpha_vantage::crypto::CryptoFunction::Daily, "BTC", "CNY")
     ///         .json()
@@ -175,7 +321,8 @@ impl Crypto {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let crypto = api
     ///         .crypto(alpha_vantage::crypto::CryptoFunction::Daily, "BTC", "CNY")
     ///         .json()
@@ -195,7 +342,8 @@ impl Crypto {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let crypto = api
     ///         .crypto(alpha_vantage::crypto::CryptoFunction::Daily, "BTC", "CNY")
     ///         .json()
@@ -215,7 +363,8 @@ impl Crypto {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let crypto = api
     ///         .crypto(alpha_vantage::crypto::CryptoFunction::Daily, "BTC", "CNY")
     ///         .json()
@@ -242,7 +391,7 @@ impl Crypto {
         self.return_meta_string("time zone")
     }
 
-    /// Return a data
+    /// Return a data sorted chronologically
     #[must_use]
     pub fn data(&self) -> &Vec<Data> {
         &self.data
@@ -267,13 +416,13 @@ impl Crypto {
 #[derive(Deserialize, Clone)]
 struct DataHelper {
     #[serde(rename = "1b. open (USD)", deserialize_with = "from_str")]
-    open_usd: f64,
+    open_usd: Price,
     #[serde(rename = "2b. high (USD)", deserialize_with = "from_str")]
-    high_usd: f64,
+    high_usd: Price,
     #[serde(rename = "3b. low (USD)", deserialize_with = "from_str")]
-    low_usd: f64,
+    low_usd: Price,
     #[serde(rename = "4b. close (USD)", deserialize_with = "from_str")]
-    close_usd: f64,
+    close_usd: Price,
     #[serde(rename = "5. volume", deserialize_with = "from_str")]
     volume: f64,
     #[serde(rename = "6. market cap (USD)", deserialize_with = "from_str")]
@@ -298,87 +447,82 @@ pub(crate) struct CryptoHelper {
 }
 
 impl CryptoHelper {
-    /// Function which convert `CryptoHelper` to `Crypto`
+    /// Function which convert `CryptoHelper` to `Crypto`. Return
+    /// `Error::AlphaVantageInvalidData` if market value cannot be parsed
     fn convert(self) -> Result<Crypto> {
         detect_common_helper_error(self.information, self.error_message, self.note)?;
 
-        if self.meta_data.is_none() || self.data.is_none() {
+        let (Some(meta_data), Some(crypto_data)) = (self.meta_data, self.data) else {
             return Err(Error::EmptyResponse);
-        }
+        };
 
         let mut vec_data = Vec::new();
-        // Can use unwrap here is none condition is checked already
-        for value in self.data.unwrap().values() {
-            for key in value.keys() {
-                let data_helper = value
-                    .get(key)
-                    .expect("failed to get value from crypto hashmap");
-
+        for value in crypto_data.values() {
+            for (key, data_helper) in value {
                 let mut data = Data {
-                    time: key.to_string(),
+                    time: key.clone(),
                     usd_open: data_helper.open_usd,
                     usd_high: data_helper.high_usd,
                     usd_low: data_helper.low_usd,
                     usd_close: data_helper.close_usd,
                     market_cap: data_helper.market_cap,
                     volume: data_helper.volume,
+                    #[cfg(feature = "chrono")]
+                    time_zone: meta_data.time_zone.clone(),
                     ..Data::default()
                 };
 
-                for key in data_helper.market_data.keys() {
-                    let value = &data_helper.market_data[key];
-                    let f64_value = f64::from_str(value).unwrap();
-                    if key.contains("1a") {
-                        data.market_open = f64_value;
-                    } else if key.contains("2a") {
-                        data.market_high = f64_value;
-                    } else if key.contains("3a") {
-                        data.market_low = f64_value;
-                    } else if key.contains("4a") {
-                        data.market_close = f64_value;
+                for (market_key, value) in &data_helper.market_data {
+                    let price = Price::from_str(value).map_err(|_| {
+                        Error::AlphaVantageInvalidData(format!(
+                            "invalid value {value} for {market_key} at {key}"
+                        ))
+                    })?;
+                    if market_key.contains("1a") {
+                        data.market_open = price;
+                    } else if market_key.contains("2a") {
+                        data.market_high = price;
+                    } else if market_key.contains("3a") {
+                        data.market_low = price;
+                    } else if market_key.contains("4a") {
+                        data.market_close = price;
                     }
                 }
                 vec_data.push(data);
             }
         }
 
+        vec_data.sort_by(|first, second| first.time.cmp(&second.time));
         Ok(Crypto {
             data: vec_data,
-            meta_data: self.meta_data.unwrap(),
+            meta_data,
         })
     }
 }
 
-impl FindData for Vec<Data> {
-    fn find(&self, time: &str) -> Option<&<Self as IntoIterator>::Item> {
-        self.iter().find(|&data| data.time == time)
+impl Candle for Data {
+    fn time(&self) -> &str {
+        &self.time
     }
 
-    fn latest(&self) -> <Self as IntoIterator>::Item {
-        let mut latest = &Data::default();
-        for data in self {
-            if latest.time < data.time {
-                latest = data;
-            }
-        }
-        latest.clone()
+    fn open(&self) -> f64 {
+        self.market_open.value
     }
 
-    fn latest_n(&self, n: usize) -> Result<Vec<&<Self as IntoIterator>::Item>> {
-        let mut time_list = self.iter().map(|data| &data.time).collect::<Vec<_>>();
-        time_list.sort_by_key(|w| cmp::Reverse(*w));
-
-        if n > time_list.len() {
-            return Err(Error::DesiredNumberOfDataNotPresent(time_list.len()));
-        }
+    fn high(&self) -> f64 {
+        self.market_high.value
+    }
 
-        let mut full_list = Vec::<&Data>::new();
+    fn low(&self) -> f64 {
+        self.market_low.value
+    }
 
-        for time in &time_list[0..n] {
-            full_list.push(self.find(time).unwrap());
-        }
+    fn close(&self) -> f64 {
+        self.market_close.value
+    }
 
-        Ok(full_list)
+    fn volume(&self) -> Option<f64> {
+        Some(self.volume)
     }
 }
 
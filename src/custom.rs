@@ -6,7 +6,7 @@ use serde::Deserialize;
 use serde_json::Value;
 
 use crate::api::ApiClient;
-use crate::error::{detect_common_helper_error, Error, Result};
+use crate::error::{decode_error, detect_common_helper_error, Result};
 /// struct used for helping creation of custom url
 #[derive(Debug, Deserialize)]
 pub(crate) struct CustomHelper {
@@ -27,8 +27,8 @@ impl CustomHelper {
     {
         detect_common_helper_error(self.information, self.error_message, self.note)?;
         let data = self.extras;
-        T::deserialize(MapDeserializer::new(data.into_iter()))
-            .map_err(|_| Error::DecodeJsonToStruct)
+        let deserializer = MapDeserializer::<_, serde_json::Error>::new(data.into_iter());
+        serde_path_to_error::deserialize(deserializer).map_err(|error| decode_error(&error, None))
     }
 }
 
@@ -78,4 +78,17 @@ impl<'a> CustomBuilder<'a> {
         let custom_helper: CustomHelper = self.api_client.get_json(&url).await?;
         custom_helper.convert()
     }
+
+    /// Returns JSON data struct by blocking current thread
+    ///
+    /// # Errors
+    /// Raise error if data obtained cannot be properly converted to struct or
+    /// API returns any 4 possible known errors
+    #[cfg(feature = "blocking")]
+    pub fn json_blocking<T>(&self) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        futures_executor::block_on(self.json())
+    }
 }
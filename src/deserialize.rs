@@ -1,6 +1,128 @@
+use std::collections::HashMap;
+use std::fmt;
 use std::str::FromStr;
 
-use serde::de::{Deserialize, Deserializer, Error};
+use serde::de::{Deserializer, Error, Unexpected, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::error;
+
+/// Numeric value returned by API. Exact decimal value parsed from same string
+/// is also stored when `decimal` feature is enabled. Decimal is None if value
+/// cannot be represented as decimal such as value out of decimal range
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct Price {
+    pub(crate) value: f64,
+    #[cfg(feature = "decimal")]
+    pub(crate) decimal: Option<rust_decimal::Decimal>,
+}
+
+impl FromStr for Price {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            value: f64::from_str(s).map_err(|err| err.to_string())?,
+            #[cfg(feature = "decimal")]
+            decimal: rust_decimal::Decimal::from_str(s)
+                .or_else(|_| rust_decimal::Decimal::from_scientific(s))
+                .ok(),
+        })
+    }
+}
+
+/// Price is serialized as string of exact decimal when `decimal` feature is
+/// enabled so exact value is restored on deserialization, otherwise it is
+/// serialized as number
+impl Serialize for Price {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        #[cfg(feature = "decimal")]
+        if let Some(decimal) = self.decimal {
+            return serializer.collect_str(&decimal);
+        }
+        serializer.serialize_f64(self.value)
+    }
+}
+
+impl<'de> Deserialize<'de> for Price {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        from_str(deserializer)
+    }
+}
+
+/// Visitor which accepts string returned by API as well as number, boolean and
+/// null so value serialized in normalized format can be deserialized again
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Option<String>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a string or a number")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+        Ok(Some(v))
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+/// Deserialize value as string. Returns None for null value
+fn deserialize_value<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(ValueVisitor)
+}
+
+/// Deserialize non null value as string
+fn deserialize_string<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_value(deserializer)?
+        .ok_or_else(|| D::Error::invalid_type(Unexpected::Unit, &"a string or a number"))
+}
 
 pub(crate) fn from_str<'de, T, D>(deserializer: D) -> Result<T, D::Error>
 where
@@ -8,18 +130,23 @@ where
     T::Err: std::fmt::Display,
     D: Deserializer<'de>,
 {
-    let s = String::deserialize(deserializer)?;
+    let s = deserialize_string(deserializer)?;
     T::from_str(&s).map_err(Error::custom)
 }
 
+/// Deserialize value into Option. `None` and `-` which are returned by API for
+/// unavailable value and null are deserialized as None
 pub(crate) fn from_none_str<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
 where
     T: FromStr,
     T::Err: std::fmt::Display,
     D: Deserializer<'de>,
 {
-    let s = String::deserialize(deserializer)?.to_lowercase();
-    if &s == "none" {
+    let Some(s) = deserialize_value(deserializer)? else {
+        return Ok(None);
+    };
+    let s = s.to_lowercase();
+    if &s == "none" || &s == "-" {
         Ok(None)
     } else {
         match T::from_str(&s) {
@@ -30,11 +157,94 @@ where
     }
 }
 
+/// Deserialize value into Option. `.` and `null` which are returned by API for
+/// unavailable value, empty string and null are deserialized as None
+pub(crate) fn from_missing_str<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+    D: Deserializer<'de>,
+{
+    match deserialize_value(deserializer)? {
+        None => Ok(None),
+        Some(s) if matches!(s.trim(), "." | "null" | "") => Ok(None),
+        Some(s) => T::from_str(&s).map(Some).map_err(Error::custom),
+    }
+}
+
 pub(crate) fn percent_f64<'de, D>(deserializer: D) -> Result<f64, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let mut s = String::deserialize(deserializer)?;
-    s.pop();
-    f64::from_str(&s).map_err(Error::custom)
+    let s = deserialize_string(deserializer)?;
+    f64::from_str(s.strip_suffix('%').unwrap_or(&s)).map_err(Error::custom)
+}
+
+/// Return value of first key present in meta data
+pub(crate) fn meta_value<'a>(
+    meta_data: &'a HashMap<String, String>,
+    keys: &[&str],
+) -> error::Result<&'a String> {
+    keys.iter()
+        .find_map(|key| meta_data.get(*key))
+        .ok_or_else(|| {
+            error::Error::AlphaVantageInvalidData(format!(
+                "meta data does not contain {}",
+                keys.join(" or ")
+            ))
+        })
+}
+
+/// Parse optional string value into T
+pub(crate) fn option_from_str<T>(value: Option<&String>, field: &str) -> error::Result<Option<T>>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    value
+        .map(|value| {
+            T::from_str(value).map_err(|err| {
+                error::Error::AlphaVantageInvalidData(format!(
+                    "failed to parse {field} value {value}: {err}"
+                ))
+            })
+        })
+        .transpose()
+}
+
+/// Parse time zone returned in meta data
+#[cfg(feature = "chrono")]
+pub(crate) fn parse_time_zone(time_zone: &str) -> error::Result<chrono_tz::Tz> {
+    time_zone.parse().map_err(|_| {
+        error::Error::AlphaVantageInvalidData(format!("unknown time zone {time_zone}"))
+    })
+}
+
+/// Parse date in `YYYY-MM-DD` format
+#[cfg(feature = "chrono")]
+pub(crate) fn parse_date(date: &str) -> error::Result<chrono::NaiveDate> {
+    chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|err| error::Error::AlphaVantageInvalidData(format!("invalid date {date}: {err}")))
+}
+
+/// Parse date or date time in local time of time zone. Date without time is
+/// considered as start of day
+#[cfg(feature = "chrono")]
+pub(crate) fn parse_datetime(
+    time: &str,
+    time_zone: chrono_tz::Tz,
+) -> error::Result<chrono::DateTime<chrono_tz::Tz>> {
+    use chrono::{NaiveDateTime, NaiveTime, TimeZone};
+
+    let naive = NaiveDateTime::parse_from_str(time, "%Y-%m-%d %H:%M:%S")
+        .or_else(|_| NaiveDateTime::parse_from_str(time, "%Y-%m-%d %H:%M"))
+        .or_else(|_| parse_date(time).map(|date| date.and_time(NaiveTime::MIN)))?;
+    time_zone
+        .from_local_datetime(&naive)
+        .earliest()
+        .ok_or_else(|| {
+            error::Error::AlphaVantageInvalidData(format!(
+                "time {time} does not exist in time zone {time_zone}"
+            ))
+        })
 }
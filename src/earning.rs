@@ -4,18 +4,22 @@
 //! interest. Quarterly data also includes analyst estimates and surprise
 //! metrics.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::api::ApiClient;
 use crate::deserialize::{from_none_str, from_str};
 use crate::error::{detect_common_helper_error, Error, Result};
 
 /// Struct to store information of annual earning
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct Annual {
-    #[serde(rename = "fiscalDateEnding")]
+    #[serde(rename(deserialize = "fiscalDateEnding"), alias = "fiscal_date_ending")]
     fiscal_date_ending: String,
-    #[serde(rename = "reportedEPS", deserialize_with = "from_str")]
+    #[serde(
+        rename(deserialize = "reportedEPS"),
+        alias = "reported_eps",
+        deserialize_with = "from_str"
+    )]
     reported_eps: f64,
 }
 
@@ -34,19 +38,35 @@ impl Annual {
 }
 
 /// Struct to store information of quarterly earning
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct Quarterly {
-    #[serde(rename = "fiscalDateEnding")]
+    #[serde(rename(deserialize = "fiscalDateEnding"), alias = "fiscal_date_ending")]
     fiscal_date_ending: String,
-    #[serde(rename = "reportedDate")]
+    #[serde(rename(deserialize = "reportedDate"), alias = "reported_date")]
     reported_date: String,
-    #[serde(rename = "reportedEPS", deserialize_with = "from_none_str")]
+    #[serde(
+        rename(deserialize = "reportedEPS"),
+        alias = "reported_eps",
+        deserialize_with = "from_none_str"
+    )]
     reported_eps: Option<f64>,
-    #[serde(rename = "estimatedEPS", deserialize_with = "from_str")]
+    #[serde(
+        rename(deserialize = "estimatedEPS"),
+        alias = "estimated_eps",
+        deserialize_with = "from_str"
+    )]
     estimated_eps: f64,
-    #[serde(rename = "surprise", deserialize_with = "from_none_str")]
+    #[serde(
+        rename(deserialize = "surprise"),
+        alias = "surprise",
+        deserialize_with = "from_none_str"
+    )]
     surprise: Option<f64>,
-    #[serde(rename = "surprisePercentage", deserialize_with = "from_none_str")]
+    #[serde(
+        rename(deserialize = "surprisePercentage"),
+        alias = "surprise_percentage",
+        deserialize_with = "from_none_str"
+    )]
     surprise_percentage: Option<f64>,
 }
 
@@ -91,7 +111,13 @@ impl Quarterly {
 }
 
 /// Struct to store earning for symbol
-#[derive(Debug, Default)]
+///
+/// Serialized as object with `symbol`, `annual_earning` containing list of
+/// object with `fiscal_date_ending` and `reported_eps` and `quarterly_earning`
+/// containing list of object with `fiscal_date_ending`, `reported_date`,
+/// `reported_eps`, `estimated_eps`, `surprise` and `surprise_percentage`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(clippy::struct_field_names)]
 pub struct Earning {
     symbol: String,
     annual_earning: Vec<Annual>,
@@ -105,7 +131,8 @@ impl Earning {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let earning = api.earning("IBM").json().await.unwrap();
     ///     let symbol = earning.symbol();
     ///     assert_eq!(symbol, "IBM");
@@ -8,21 +8,21 @@
 //!
 //! [economic_indicator]: https://www.alphavantage.co/documentation/#economic-indicators
 
-use std::cmp;
-
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::api::ApiClient;
-use crate::deserialize::from_str;
+use crate::deserialize::from_missing_str;
+#[cfg(feature = "chrono")]
+use crate::deserialize::parse_date;
 use crate::error::{detect_common_helper_error, Error, Result};
 use crate::vec_trait::FindData;
 
 /// Struct for storing a data values
-#[derive(Default, Debug, Deserialize, Clone)]
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
 pub struct Data {
     date: String,
-    #[serde(deserialize_with = "from_str")]
-    value: f64,
+    #[serde(deserialize_with = "from_missing_str")]
+    value: Option<f64>,
 }
 
 impl Data {
@@ -32,50 +32,55 @@ impl Data {
         &self.date
     }
 
-    /// Return value for Data
+    /// Return date as naive date. Economic indicator does not contain any time
+    /// zone information
+    ///
+    /// # Errors
+    /// Raise error if date cannot be parsed
+    #[cfg(feature = "chrono")]
+    pub fn naive_date(&self) -> Result<chrono::NaiveDate> {
+        parse_date(&self.date)
+    }
+
+    /// Return value for Data. Returns None if observation is not available
     #[must_use]
-    pub fn value(&self) -> f64 {
+    pub fn value(&self) -> Option<f64> {
         self.value
     }
 }
 
 impl FindData for Vec<Data> {
-    #[must_use]
     fn find(&self, time: &str) -> Option<&<Self as IntoIterator>::Item> {
-        self.iter().find(|&data| data.date == time)
+        self.binary_search_by(|data| data.date.as_str().cmp(time))
+            .ok()
+            .map(|index| &self[index])
     }
 
-    #[must_use]
     fn latest(&self) -> <Self as IntoIterator>::Item {
-        let mut latest = &Data::default();
-        for data in self {
-            if latest.date < data.date {
-                latest = data;
-            }
-        }
-        latest.clone()
+        self.last().cloned().unwrap_or_default()
     }
 
     fn latest_n(&self, n: usize) -> Result<Vec<&<Self as IntoIterator>::Item>> {
-        let mut date_list = self.iter().map(|data| &data.date).collect::<Vec<_>>();
-        date_list.sort_by_key(|w| cmp::Reverse(*w));
-
-        if n > date_list.len() {
-            return Err(Error::DesiredNumberOfDataNotPresent(date_list.len()));
-        }
-
-        let mut full_list = Vec::<&Data>::new();
-
-        for date in &date_list[0..n] {
-            full_list.push(self.find(date).unwrap());
+        if n > self.len() {
+            return Err(Error::DesiredNumberOfDataNotPresent(self.len()));
         }
+        Ok(self[self.len() - n..].iter().rev().collect())
+    }
 
-        Ok(full_list)
+    fn range(&self, start: &str, end: &str) -> &[<Self as IntoIterator>::Item] {
+        let start_index = self.partition_point(|data| data.date.as_str() < start);
+        let end_index = self.partition_point(|data| data.date.as_str() < end);
+        &self[start_index..end_index.max(start_index)]
     }
 }
 
 /// Struct for indicator
-#[derive(Default, Debug)]
+///
+/// Serialized as object with `name`, `interval`, `unit` and `data` containing
+/// list of object with `date` and `value`. Unavailable value is serialized as
+/// null
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "EconomicIndicatorRecord")]
 pub struct EconomicIndicator {
     name: String,
     interval: String,
@@ -83,6 +88,29 @@ pub struct EconomicIndicator {
     data: Vec<Data>,
 }
 
+/// Normalized representation of `EconomicIndicator` used for sorting data
+/// while deserializing
+#[derive(Deserialize)]
+struct EconomicIndicatorRecord {
+    name: String,
+    interval: String,
+    unit: String,
+    data: Vec<Data>,
+}
+
+impl From<EconomicIndicatorRecord> for EconomicIndicator {
+    fn from(record: EconomicIndicatorRecord) -> Self {
+        let mut data = record.data;
+        data.sort_by(|first, second| first.date.cmp(&second.date));
+        Self {
+            name: record.name,
+            interval: record.interval,
+            unit: record.unit,
+            data,
+        }
+    }
+}
+
 impl EconomicIndicator {
     /// Return name of economic indicator
     #[must_use]
@@ -102,11 +130,102 @@ impl EconomicIndicator {
         &self.unit
     }
 
-    /// Return data as a vector
+    /// Return data as a vector sorted by date
     #[must_use]
     pub fn data(&self) -> &Vec<Data> {
         &self.data
     }
+
+    /// Remove observations whose value is not available
+    ///
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let economic = api
+    ///         .economic_indicator("TREASURY_YIELD")
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     assert_eq!(economic.data()[2].value(), None);
+    ///     let economic = economic.drop_missing();
+    ///     assert!(economic.data().iter().all(|data| data.value().is_some()));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn drop_missing(mut self) -> Self {
+        self.data.retain(|data| data.value.is_some());
+        self
+    }
+
+    /// Replace unavailable value with value of previous observation. Leading
+    /// observations without previous value are kept unavailable
+    ///
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let economic = api
+    ///         .economic_indicator("TREASURY_YIELD")
+    ///         .json()
+    ///         .await
+    ///         .unwrap()
+    ///         .forward_fill();
+    ///     assert_eq!(economic.data()[2].value(), economic.data()[1].value());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn forward_fill(mut self) -> Self {
+        let mut previous = None;
+        for data in &mut self.data {
+            match data.value {
+                Some(value) => previous = Some(value),
+                None => data.value = previous,
+            }
+        }
+        self
+    }
+
+    /// Replace unavailable value by linear interpolation between nearest
+    /// available observations. Observations are considered evenly spaced.
+    /// Leading and trailing observations without available value on both side
+    /// are kept unavailable
+    ///
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let economic = api
+    ///         .economic_indicator("TREASURY_YIELD")
+    ///         .json()
+    ///         .await
+    ///         .unwrap()
+    ///         .interpolate();
+    ///     assert_eq!(economic.data()[2].value(), Some(4.625));
+    /// }
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn interpolate(mut self) -> Self {
+        let mut previous: Option<(usize, f64)> = None;
+        for index in 0..self.data.len() {
+            let Some(value) = self.data[index].value else {
+                continue;
+            };
+            if let Some((previous_index, previous_value)) = previous {
+                let steps = (index - previous_index) as f64;
+                for (offset, data) in self.data[previous_index + 1..index].iter_mut().enumerate() {
+                    let ratio = (offset + 1) as f64 / steps;
+                    data.value = Some(previous_value + (value - previous_value) * ratio);
+                }
+            }
+            previous = Some((index, value));
+        }
+        self
+    }
 }
 
 /// Struct for helping indicator struct
@@ -127,18 +246,17 @@ pub(crate) struct EconomicIndicatorHelper {
 impl EconomicIndicatorHelper {
     fn convert(self) -> Result<EconomicIndicator> {
         detect_common_helper_error(self.information, self.error_message, self.note)?;
-        if self.name.is_none()
-            || self.interval.is_none()
-            || self.unit.is_none()
-            || self.data.is_none()
-        {
+        let (Some(name), Some(interval), Some(unit), Some(mut data)) =
+            (self.name, self.interval, self.unit, self.data)
+        else {
             return Err(Error::EmptyResponse);
-        }
+        };
+        data.sort_by(|first, second| first.date.cmp(&second.date));
         Ok(EconomicIndicator {
-            name: self.name.unwrap(),
-            interval: self.interval.unwrap(),
-            unit: self.unit.unwrap(),
-            data: self.data.unwrap(),
+            name,
+            interval,
+            unit,
+            data,
         })
     }
 }
@@ -149,10 +267,11 @@ pub struct EconomicIndicatorBuilder<'a> {
     function: &'a str,
     interval: Option<EconomicIndicatorInterval>,
     maturity: Option<EconomicIndicatorMaturity>,
+    typed_function: Option<EconomicIndicatorFunction>,
 }
 
 impl<'a> EconomicIndicatorBuilder<'a> {
-    crate::json_data_struct!(EconomicIndicator, EconomicIndicatorHelper);
+    crate::json_data_struct!(EconomicIndicator, EconomicIndicatorHelper, validate);
 
     /// Create new `EconomicIndicatorBuilder` form `APIClient`
     #[must_use]
@@ -162,9 +281,20 @@ impl<'a> EconomicIndicatorBuilder<'a> {
             function,
             interval: None,
             maturity: None,
+            typed_function: None,
         }
     }
 
+    /// Create new `EconomicIndicatorBuilder` from `APIClient` using typed
+    /// function. Interval and maturity are checked against values supported by
+    /// function before request is sent
+    #[must_use]
+    pub fn with_function(api_client: &'a ApiClient, function: EconomicIndicatorFunction) -> Self {
+        let mut builder = Self::new(api_client, function.function());
+        builder.typed_function = Some(function);
+        builder
+    }
+
     /// Set interval for API
     pub fn interval(&mut self, interval: EconomicIndicatorInterval) -> &mut Self {
         self.interval = Some(interval);
@@ -177,6 +307,13 @@ impl<'a> EconomicIndicatorBuilder<'a> {
         self
     }
 
+    fn validate(&self) -> Result<()> {
+        if let Some(typed_function) = &self.typed_function {
+            typed_function.validate(self.interval.as_ref(), self.maturity.as_ref())?;
+        }
+        Ok(())
+    }
+
     fn create_url(&self) -> String {
         let mut created_link = format!("query?function={}", &self.function);
 
@@ -188,14 +325,19 @@ impl<'a> EconomicIndicatorBuilder<'a> {
                 EconomicIndicatorInterval::Quarterly => {
                     created_link.push_str("&interval=quarterly");
                 }
-                EconomicIndicatorInterval::Annually => created_link.push_str("&interval=annually"),
+                EconomicIndicatorInterval::SemiAnnually => {
+                    created_link.push_str("&interval=semiannual");
+                }
+                EconomicIndicatorInterval::Annually => created_link.push_str("&interval=annual"),
             }
         }
 
         if let Some(maturity) = &self.maturity {
             match maturity {
                 EconomicIndicatorMaturity::ThreeMonth => created_link.push_str("&maturity=3month"),
+                EconomicIndicatorMaturity::TwoYear => created_link.push_str("&maturity=2year"),
                 EconomicIndicatorMaturity::FiveYear => created_link.push_str("&maturity=5year"),
+                EconomicIndicatorMaturity::SevenYear => created_link.push_str("&maturity=7year"),
                 EconomicIndicatorMaturity::TenYear => created_link.push_str("&maturity=10year"),
                 EconomicIndicatorMaturity::ThirtyYear => created_link.push_str("&maturity=30year"),
             }
@@ -206,7 +348,7 @@ impl<'a> EconomicIndicatorBuilder<'a> {
 }
 
 /// Enum for declaring interval for economic indicator
-#[derive(Clone)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum EconomicIndicatorInterval {
     /// daily interval
     Daily,
@@ -216,19 +358,186 @@ pub enum EconomicIndicatorInterval {
     Monthly,
     /// quarterly interval
     Quarterly,
+    /// semiannual interval
+    SemiAnnually,
     /// annually interval
     Annually,
 }
 
 /// Enum for declaring maturity for economic indicator
-#[derive(Clone)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum EconomicIndicatorMaturity {
     /// 3 month maturity
     ThreeMonth,
+    /// 2 year maturity
+    TwoYear,
     /// 5 year maturity
     FiveYear,
+    /// 7 year maturity
+    SevenYear,
     /// 10 year maturity
     TenYear,
     /// 30 year maturity
     ThirtyYear,
 }
+
+/// Enum for declaring economic indicator function
+///
+/// ```
+/// use alpha_vantage::economic_indicator::{EconomicIndicatorFunction, EconomicIndicatorInterval};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+///     let api = alpha_vantage::set_api("demo", client);
+///     let function = EconomicIndicatorFunction::Cpi;
+///     assert!(function.supports_interval(EconomicIndicatorInterval::Monthly));
+///     assert!(!function.supports_maturity());
+///     let economic = api
+///         .economic(EconomicIndicatorFunction::RealGdpPerCapita)
+///         .json()
+///         .await
+///         .unwrap();
+///     assert_eq!(economic.name(), "Real Gross Domestic Product per Capita");
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EconomicIndicatorFunction {
+    /// Annual and quarterly real GDP of the United States
+    RealGdp,
+    /// Quarterly real GDP per capita of the United States
+    RealGdpPerCapita,
+    /// Daily, weekly and monthly US treasury yield of given maturity
+    TreasuryYield,
+    /// Daily, weekly and monthly federal funds rate of the United States
+    FederalFundsRate,
+    /// Monthly and semiannual consumer price index of the United States
+    Cpi,
+    /// Annual inflation rate of the United States
+    Inflation,
+    /// Monthly advance retail sales of the United States
+    RetailSales,
+    /// Monthly manufacturers new orders of durable goods of the United States
+    Durables,
+    /// Monthly unemployment rate of the United States
+    Unemployment,
+    /// Monthly total nonfarm payroll of the United States
+    NonfarmPayroll,
+}
+
+impl EconomicIndicatorFunction {
+    /// Return function name used by API
+    #[must_use]
+    pub fn function(&self) -> &'static str {
+        match self {
+            EconomicIndicatorFunction::RealGdp => "REAL_GDP",
+            EconomicIndicatorFunction::RealGdpPerCapita => "REAL_GDP_PER_CAPITA",
+            EconomicIndicatorFunction::TreasuryYield => "TREASURY_YIELD",
+            EconomicIndicatorFunction::FederalFundsRate => "FEDERAL_FUNDS_RATE",
+            EconomicIndicatorFunction::Cpi => "CPI",
+            EconomicIndicatorFunction::Inflation => "INFLATION",
+            EconomicIndicatorFunction::RetailSales => "RETAIL_SALES",
+            EconomicIndicatorFunction::Durables => "DURABLES",
+            EconomicIndicatorFunction::Unemployment => "UNEMPLOYMENT",
+            EconomicIndicatorFunction::NonfarmPayroll => "NONFARM_PAYROLL",
+        }
+    }
+
+    /// Return intervals supported by function. Function which returns empty
+    /// slice does not accept interval
+    #[must_use]
+    pub fn intervals(&self) -> &'static [EconomicIndicatorInterval] {
+        match self {
+            EconomicIndicatorFunction::RealGdp => {
+                &[
+                    EconomicIndicatorInterval::Quarterly,
+                    EconomicIndicatorInterval::Annually,
+                ]
+            }
+            EconomicIndicatorFunction::TreasuryYield
+            | EconomicIndicatorFunction::FederalFundsRate => {
+                &[
+                    EconomicIndicatorInterval::Daily,
+                    EconomicIndicatorInterval::Weekly,
+                    EconomicIndicatorInterval::Monthly,
+                ]
+            }
+            EconomicIndicatorFunction::Cpi => {
+                &[
+                    EconomicIndicatorInterval::Monthly,
+                    EconomicIndicatorInterval::SemiAnnually,
+                ]
+            }
+            EconomicIndicatorFunction::RealGdpPerCapita
+            | EconomicIndicatorFunction::Inflation
+            | EconomicIndicatorFunction::RetailSales
+            | EconomicIndicatorFunction::Durables
+            | EconomicIndicatorFunction::Unemployment
+            | EconomicIndicatorFunction::NonfarmPayroll => &[],
+        }
+    }
+
+    /// Return true if function supports interval
+    #[must_use]
+    pub fn supports_interval(&self, interval: EconomicIndicatorInterval) -> bool {
+        self.intervals().contains(&interval)
+    }
+
+    /// Return true if function accepts maturity
+    #[must_use]
+    pub fn supports_maturity(&self) -> bool {
+        matches!(self, EconomicIndicatorFunction::TreasuryYield)
+    }
+
+    /// Validate interval and maturity for function
+    ///
+    /// ```
+    /// use alpha_vantage::economic_indicator::{
+    ///     EconomicIndicatorFunction, EconomicIndicatorInterval, EconomicIndicatorMaturity,
+    /// };
+    ///
+    /// let function = EconomicIndicatorFunction::TreasuryYield;
+    /// assert!(
+    ///     function
+    ///         .validate(
+    ///             Some(&EconomicIndicatorInterval::Daily),
+    ///             Some(&EconomicIndicatorMaturity::SevenYear)
+    ///         )
+    ///         .is_ok()
+    /// );
+    /// assert!(
+    ///     function
+    ///         .validate(Some(&EconomicIndicatorInterval::Annually), None)
+    ///         .is_err()
+    /// );
+    /// assert!(
+    ///     EconomicIndicatorFunction::Cpi
+    ///         .validate(None, Some(&EconomicIndicatorMaturity::TenYear))
+    ///         .is_err()
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if interval or maturity is not supported by function
+    pub fn validate(
+        &self,
+        interval: Option<&EconomicIndicatorInterval>,
+        maturity: Option<&EconomicIndicatorMaturity>,
+    ) -> Result<()> {
+        if let Some(interval) = interval {
+            if !self.supports_interval(*interval) {
+                return Err(Error::InvalidParameter(format!(
+                    "interval {interval:?} is not supported by {}",
+                    self.function()
+                )));
+            }
+        }
+        if maturity.is_some() && !self.supports_maturity() {
+            return Err(Error::InvalidParameter(format!(
+                "maturity is not supported by {}",
+                self.function()
+            )));
+        }
+        Ok(())
+    }
+}
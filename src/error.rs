@@ -1,6 +1,11 @@
 //! Module which contains all types of error for alpha vantage crates
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
 use thiserror::Error;
 
+/// Maximum number of character of raw output stored in decode error
+const SNIPPET_LENGTH: usize = 256;
+
 /// Result type for alpha vantage crate
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -8,24 +13,72 @@ pub type Result<T> = std::result::Result<T, Error>;
 #[non_exhaustive]
 /// Main error/failure enum
 pub enum Error {
+    /// Error which is raised if API returned rate limit message because call
+    /// frequency of API key is exceeded
+    ///
+    /// ```
+    /// use alpha_vantage::error::{Error, RateLimitPeriod};
+    /// use alpha_vantage::mock::MockClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = MockClient::new().response(
+    ///         "function=GLOBAL_QUOTE",
+    ///         r#"{"Information": "Thank you for using Alpha Vantage! Our standard API rate limit is 25 requests per day."}"#,
+    ///     );
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let error = api.quote("MSFT").json().await.err().unwrap();
+    ///     assert!(matches!(
+    ///         error,
+    ///         Error::RateLimited {
+    ///             period: RateLimitPeriod::Day,
+    ///             ..
+    ///         }
+    ///     ));
+    /// }
+    /// ```
+    #[error("rate limit exceeded ({period}): {message}")]
+    RateLimited {
+        /// Period whose limit is exceeded
+        period: RateLimitPeriod,
+        /// Message returned by API
+        message: String,
+    },
+
+    /// Error which is raised if API endpoint is only available for premium
+    /// API key
+    #[error("premium endpoint: {0}")]
+    PremiumEndpoint(String),
+
+    /// Error which is raised if API key is invalid or missing, or demo API key
+    /// is used for non demo call
+    #[error("invalid api key: {0}")]
+    InvalidApiKey(String),
+
+    /// Error which is raised if API call is invalid such as invalid symbol or
+    /// invalid function
+    #[error("invalid api call: {0}")]
+    InvalidApiCall(String),
+
     /// Error which is raised if information is returned by API instead of data
-    /// from API
+    /// from API and information cannot be classified
     #[error("information: {0}")]
     AlphaVantageInformation(String),
 
-    /// Error which is raised if error_message is returned by API instead of
-    /// data from API
+    /// Error which is raised if `error_message` is returned by API instead of
+    /// data from API and error message cannot be classified
     #[error("error_message: {0}")]
     AlphaVantageErrorMessage(String),
 
     /// Error which is raised if note is returned by API instead of data from
-    /// API
+    /// API and note cannot be classified
     #[error("note: {0}")]
     AlphaVantageNote(String),
 
-    /// Error which is raised if alpha vantage server returns some invalid data
-    #[error("alpha vantage returns invalid data")]
-    AlphaVantageInvalidData,
+    /// Error which is raised if alpha vantage server returns some invalid data.
+    /// Contains context about which data is invalid
+    #[error("alpha vantage returns invalid data: {0}")]
+    AlphaVantageInvalidData(String),
 
     /// Error which is raised when desired number of data is not present
     #[error("desired number of latest data not found try using less than {0} as n")]
@@ -40,28 +93,390 @@ pub enum Error {
     #[error("failed to get output from sever")]
     GetRequestFailed,
 
+    /// Error which is raised by mock client or replaying recording client if no
+    /// response is present for request. Contains query of request without API
+    /// key
+    ///
+    /// ```
+    /// use alpha_vantage::error::Error;
+    /// use alpha_vantage::mock::MockClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", MockClient::new());
+    ///     let error = api.quote("MSFT").json().await.err().unwrap();
+    ///     assert!(!error.is_transient());
+    ///     assert_eq!(
+    ///         error.to_string(),
+    ///         "no mock response present for request function=GLOBAL_QUOTE&symbol=MSFT"
+    ///     );
+    /// }
+    /// ```
+    #[error("no mock response present for request {0}")]
+    MockResponseMissing(String),
+
+    /// Error which is raised if recording client fails to write cassette
+    #[error("failed to write cassette {}", .path.display())]
+    CassetteWrite {
+        /// Path of cassette
+        path: std::path::PathBuf,
+        /// Underlying IO error
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// Error which is raised if HTTP request failed or server returned non
+    /// success status code. Contains kind of failure, status code and body
+    /// returned by server if available along with underlying error
+    #[error(
+        "http request failed ({kind}){}",
+        .status.map_or_else(String::new, |status| format!(" with status code {status}"))
+    )]
+    HttpRequestFailed {
+        /// Kind of HTTP failure
+        kind: HttpErrorKind,
+        /// Status code returned by server
+        status: Option<u16>,
+        /// Body returned by server along with non success status code
+        body: Option<String>,
+        /// Underlying error returned by client
+        #[source]
+        source: Option<Box<dyn std::error::Error + Send + Sync>>,
+    },
+
     /// Error which is raised if client fails to decode it into struct
-    #[error("failed to decode string into struct")]
-    DecodeJsonToStruct,
+    ///
+    /// ```
+    /// use alpha_vantage::error::Error;
+    /// use alpha_vantage::mock::MockClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = MockClient::new().response(
+    ///         "function=GLOBAL_QUOTE",
+    ///         r#"{"Global Quote": {"01. symbol": "MSFT", "02. open": "invalid"}}"#,
+    ///     );
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     match api.quote("MSFT").json().await {
+    ///         Err(Error::DecodeJsonToStruct { path, snippet, .. }) => {
+    ///             assert_eq!(path, "Global Quote.02. open");
+    ///             assert!(snippet.unwrap().contains("invalid"));
+    ///         }
+    ///         _ => panic!("expected decode error"),
+    ///     }
+    ///
+    ///     let client = MockClient::new().response(
+    ///         "function=SYMBOL_SEARCH&keywords=BA",
+    ///         r#"{"bestMatches": []} trailing"#,
+    ///     );
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     match api.search("BA").json().await {
+    ///         Err(Error::DecodeJsonToStruct { path, .. }) => {
+    ///             assert_eq!(path, ".");
+    ///         }
+    ///         _ => panic!("expected decode error"),
+    ///     }
+    /// }
+    /// ```
+    #[error("failed to decode string into struct at path {path}: {message}")]
+    DecodeJsonToStruct {
+        /// Error message returned by serde
+        message: String,
+        /// JSON path of field which failed to decode
+        path: String,
+        /// Truncated snippet of raw output if available
+        snippet: Option<String>,
+    },
+
+    /// Error which is raised if CSV output returned by API cannot be decoded
+    /// into struct
+    ///
+    /// ```
+    /// use alpha_vantage::error::Error;
+    /// use alpha_vantage::mock::MockClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = MockClient::new().response(
+    ///         "function=IPO_CALENDAR",
+    ///         "symbol,name,ipoDate\nBIRK,Birkenstock Holding plc,2023-10-11\n",
+    ///     );
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     match api.ipo_calendar().csv().await {
+    ///         Err(Error::DecodeCsvToStruct { line, .. }) => assert_eq!(line, Some(2)),
+    ///         _ => panic!("expected decode error"),
+    ///     }
+    /// }
+    /// ```
+    #[error("failed to decode csv into struct at line {line:?}: {message}")]
+    DecodeCsvToStruct {
+        /// Error message returned by csv reader
+        message: String,
+        /// Line of CSV output which failed to decode if available
+        line: Option<u64>,
+    },
+
+    /// Error which is raised if parameter of request is invalid. Request is not
+    /// sent to server when this error is raised
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(String),
 
     /// Error which is raised if url is failed to get created
     #[error("failed to create url")]
     CreateUrl,
 }
 
+/// Period of rate limit which is exceeded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RateLimitPeriod {
+    /// Short term limit such as calls per minute or per second
+    Minute,
+    /// Daily limit
+    Day,
+    /// Period cannot be determined from message
+    Unknown,
+}
+
+impl std::fmt::Display for RateLimitPeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let period = match self {
+            RateLimitPeriod::Minute => "per minute",
+            RateLimitPeriod::Day => "per day",
+            RateLimitPeriod::Unknown => "unknown period",
+        };
+        write!(f, "{period}")
+    }
+}
+
+/// Kind of failure which occurred while performing HTTP request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HttpErrorKind {
+    /// Request timed out
+    Timeout,
+    /// Failed to connect to server which includes DNS resolution, TCP or TLS
+    /// connection failure
+    Connect,
+    /// Server returned non success status code
+    Status,
+    /// Failed to read response body
+    Body,
+    /// Failed to build or send request
+    Request,
+    /// Any other failure
+    Other,
+}
+
+impl std::fmt::Display for HttpErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            HttpErrorKind::Timeout => "timeout",
+            HttpErrorKind::Connect => "connection failure",
+            HttpErrorKind::Status => "non success status",
+            HttpErrorKind::Body => "failed to read body",
+            HttpErrorKind::Request => "request failure",
+            HttpErrorKind::Other => "other failure",
+        };
+        write!(f, "{kind}")
+    }
+}
+
+impl Error {
+    /// Check if error is caused by transport failure or server side failure
+    /// which can succeed if request is performed again. Only timeout,
+    /// connection failure and status code 429 or 5xx are transient
+    ///
+    /// ```
+    /// use alpha_vantage::error::{Error, HttpErrorKind};
+    /// let error = Error::HttpRequestFailed {
+    ///     kind: HttpErrorKind::Status,
+    ///     status: Some(503),
+    ///     body: None,
+    ///     source: None,
+    /// };
+    /// assert!(error.is_transient());
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "http request failed (non success status) with status code 503"
+    /// );
+    ///
+    /// let error = Error::HttpRequestFailed {
+    ///     kind: HttpErrorKind::Body,
+    ///     status: Some(200),
+    ///     body: None,
+    ///     source: None,
+    /// };
+    /// assert!(!error.is_transient());
+    /// assert!(!Error::GetRequestFailed.is_transient());
+    /// ```
+    #[must_use]
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Error::HttpRequestFailed { kind, status, .. } => {
+                match kind {
+                    HttpErrorKind::Timeout | HttpErrorKind::Connect => true,
+                    HttpErrorKind::Status => {
+                        status.is_some_and(|status| status == 429 || status >= 500)
+                    }
+                    HttpErrorKind::Body | HttpErrorKind::Request | HttpErrorKind::Other => false,
+                }
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Return truncated snippet of raw output
+fn output_snippet(output: &str) -> String {
+    let mut snippet = output.chars().take(SNIPPET_LENGTH).collect::<String>();
+    if snippet.len() < output.len() {
+        snippet.push_str("...");
+    }
+    snippet
+}
+
+/// Create `Error::DecodeJsonToStruct` from serde error along with path and
+/// truncated raw output
+pub(crate) fn decode_error<E>(error: &serde_path_to_error::Error<E>, output: Option<&str>) -> Error
+where
+    E: std::fmt::Display,
+{
+    Error::DecodeJsonToStruct {
+        message: error.inner().to_string(),
+        path: error.path().to_string(),
+        snippet: output.map(output_snippet),
+    }
+}
+
+/// Decode JSON output into struct keeping path of failing field in error.
+/// Output containing anything other than whitespace after JSON value is
+/// rejected
+pub(crate) fn decode_json<T>(output: &str) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    let deserializer = &mut serde_json::Deserializer::from_str(output);
+    let value = serde_path_to_error::deserialize(&mut *deserializer)
+        .map_err(|error| decode_error(&error, Some(output)))?;
+    deserializer.end().map_err(|error| {
+        Error::DecodeJsonToStruct {
+            message: error.to_string(),
+            path: ".".to_string(),
+            snippet: Some(output_snippet(output)),
+        }
+    })?;
+    Ok(value)
+}
+
+/// Decode CSV output into vector of struct. API returns JSON message instead of
+/// CSV when request fails so message is checked before decoding
+pub(crate) fn decode_csv<T>(output: &str) -> Result<Vec<T>>
+where
+    T: DeserializeOwned,
+{
+    if let Some(helper) = MessageHelper::from_output(output) {
+        detect_common_helper_error(helper.information, helper.error_message, helper.note)?;
+    }
+    csv::Reader::from_reader(output.as_bytes())
+        .deserialize()
+        .map(|record| {
+            record.map_err(|error| {
+                Error::DecodeCsvToStruct {
+                    message: error.to_string(),
+                    line: error.position().map(csv::Position::line),
+                }
+            })
+        })
+        .collect()
+}
+
+/// Classify message returned by API into typed error. Return None if message
+/// cannot be classified
+fn classify_message(message: &str) -> Option<Error> {
+    let lower = message.to_lowercase();
+    let message = message.to_string();
+    if lower.contains("premium endpoint") {
+        return Some(Error::PremiumEndpoint(message));
+    }
+    if lower.contains("rate limit")
+        || lower.contains("call frequency")
+        || lower.contains("more sparingly")
+    {
+        let period = if lower.contains("per minute") || lower.contains("per second") {
+            RateLimitPeriod::Minute
+        } else if lower.contains("per day") || lower.contains("daily") {
+            RateLimitPeriod::Day
+        } else {
+            RateLimitPeriod::Unknown
+        };
+        return Some(Error::RateLimited { period, message });
+    }
+    if lower.contains("apikey") || lower.contains("api key") {
+        return Some(Error::InvalidApiKey(message));
+    }
+    if lower.contains("invalid api call") {
+        return Some(Error::InvalidApiCall(message));
+    }
+    None
+}
+
+/// Detect error from information, error message or note returned by API
 pub(crate) fn detect_common_helper_error(
     information: Option<String>,
     error_message: Option<String>,
     note: Option<String>,
 ) -> Result<()> {
     if let Some(information) = information {
-        return Err(Error::AlphaVantageInformation(information));
+        return Err(
+            classify_message(&information).unwrap_or(Error::AlphaVantageInformation(information))
+        );
     }
     if let Some(error_message) = error_message {
-        return Err(Error::AlphaVantageErrorMessage(error_message));
+        return Err(classify_message(&error_message)
+            .unwrap_or(Error::AlphaVantageErrorMessage(error_message)));
     }
     if let Some(note) = note {
-        return Err(Error::AlphaVantageNote(note));
+        return Err(classify_message(&note).unwrap_or(Error::AlphaVantageNote(note)));
     }
     Ok(())
 }
+
+/// Struct used for checking if API returned message instead of data
+#[derive(Deserialize)]
+struct MessageHelper {
+    #[serde(rename = "Information")]
+    information: Option<String>,
+    #[serde(rename = "Error Message")]
+    error_message: Option<String>,
+    #[serde(rename = "Note")]
+    note: Option<String>,
+}
+
+impl MessageHelper {
+    fn from_output(output: &str) -> Option<Self> {
+        serde_json::from_str(output).ok()
+    }
+}
+
+/// Check if API output is a rate limit message which can succeed if retried
+/// later. Daily rate limit is not considered as retrying within short period
+/// does not succeed
+pub(crate) fn is_retryable_rate_limit_response(output: &str) -> bool {
+    MessageHelper::from_output(output).is_some_and(|helper| {
+        let error =
+            detect_common_helper_error(helper.information, helper.error_message, helper.note);
+        matches!(
+            error,
+            Err(Error::RateLimited { period, .. }) if period != RateLimitPeriod::Day
+        )
+    })
+}
+
+/// Check if API output contains any information, error message or note
+/// instead of data
+pub(crate) fn is_message_response(output: &str) -> bool {
+    MessageHelper::from_output(output).is_some_and(|helper| {
+        helper.information.is_some() || helper.error_message.is_some() || helper.note.is_some()
+    })
+}
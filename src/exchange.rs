@@ -8,38 +8,48 @@
 //!
 //! [exchange]: https://www.alphavantage.co/documentation/#currency-exchnage
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::api::ApiClient;
-use crate::deserialize::from_str;
+use crate::deserialize::{from_str, Price};
+#[cfg(feature = "chrono")]
+use crate::deserialize::{parse_datetime, parse_time_zone};
 use crate::error::{detect_common_helper_error, Error, Result};
 
 /// Struct used for exchanging currency
-#[derive(Default)]
+///
+/// Serialized as object with `from_code`, `from_name`, `to_code`, `to_name`,
+/// `rate`, `last_refreshed`, `time_zone`, `bid_price` and `ask_price`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct Exchange {
     real_time: RealtimeExchangeRate,
 }
 
 /// Struct Storing Real time Exchange Value
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 struct RealtimeExchangeRate {
-    #[serde(rename = "1. From_Currency Code")]
+    #[serde(rename(deserialize = "1. From_Currency Code"), alias = "from_code")]
     from_code: String,
-    #[serde(rename = "2. From_Currency Name")]
+    #[serde(rename(deserialize = "2. From_Currency Name"), alias = "from_name")]
     from_name: String,
-    #[serde(rename = "3. To_Currency Code")]
+    #[serde(rename(deserialize = "3. To_Currency Code"), alias = "to_code")]
     to_code: String,
-    #[serde(rename = "4. To_Currency Name")]
+    #[serde(rename(deserialize = "4. To_Currency Name"), alias = "to_name")]
     to_name: String,
-    #[serde(rename = "5. Exchange Rate", deserialize_with = "from_str")]
-    rate: f64,
-    #[serde(rename = "6. Last Refreshed")]
+    #[serde(
+        rename(deserialize = "5. Exchange Rate"),
+        alias = "rate",
+        deserialize_with = "from_str"
+    )]
+    rate: Price,
+    #[serde(rename(deserialize = "6. Last Refreshed"), alias = "last_refreshed")]
     last_refreshed: String,
-    #[serde(rename = "7. Time Zone")]
+    #[serde(rename(deserialize = "7. Time Zone"), alias = "time_zone")]
     time_zone: String,
-    #[serde(rename = "8. Bid Price")]
+    #[serde(rename(deserialize = "8. Bid Price"), alias = "bid_price")]
     bid_price: String,
-    #[serde(rename = "9. Ask Price")]
+    #[serde(rename(deserialize = "9. Ask Price"), alias = "ask_price")]
     ask_price: String,
 }
 
@@ -47,7 +57,46 @@ impl Exchange {
     /// Get Rate for exchange
     #[must_use]
     pub fn rate(&self) -> f64 {
-        self.real_time.rate
+        self.real_time.rate.value
+    }
+
+    /// Get rate for exchange as exact decimal. Return None if rate cannot be
+    /// represented as decimal. Exact decimal is serialized as string so it is
+    /// kept after serializing and deserializing exchange again
+    ///
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let exchange = api.exchange("BTC", "CNY").json().await.unwrap();
+    ///     assert_eq!(
+    ///         exchange.rate_decimal().unwrap().to_string(),
+    ///         "196430.53000000"
+    ///     );
+    ///
+    ///     let json = serde_json::to_string(&exchange).unwrap();
+    ///     let restored: alpha_vantage::exchange::Exchange = serde_json::from_str(&json).unwrap();
+    ///     assert_eq!(restored.rate_decimal(), exchange.rate_decimal());
+    ///
+    ///     let client = alpha_vantage::mock::MockClient::new().response(
+    ///         "function=CURRENCY_EXCHANGE_RATE",
+    ///         r#"{"Realtime Currency Exchange Rate": {"1. From_Currency Code": "BTC",
+    ///             "2. From_Currency Name": "Bitcoin", "3. To_Currency Code": "CNY",
+    ///             "4. To_Currency Name": "Chinese Yuan", "5. Exchange Rate": "1e40",
+    ///             "6. Last Refreshed": "2023-09-30 12:00:01", "7. Time Zone": "UTC",
+    ///             "8. Bid Price": "1", "9. Ask Price": "1"}}"#,
+    ///     );
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let exchange = api.exchange("BTC", "CNY").json().await.unwrap();
+    ///     assert_eq!(exchange.rate(), 1e40);
+    ///     assert!(exchange.rate_decimal().is_none());
+    /// }
+    /// ```
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn rate_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.real_time.rate.decimal
     }
 
     /// Get time when exchange rate was last refreshed along with time zone.
@@ -56,6 +105,30 @@ impl Exchange {
         &self.real_time.last_refreshed
     }
 
+    /// Get time when exchange rate was last refreshed as date time in time
+    /// zone of exchange
+    ///
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let exchange = api.exchange("BTC", "CNY").json().await.unwrap();
+    ///     let refreshed = exchange.refreshed_datetime().unwrap();
+    ///     assert_eq!(refreshed.timezone(), alpha_vantage::chrono_tz::UTC);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if time or time zone cannot be parsed
+    #[cfg(feature = "chrono")]
+    pub fn refreshed_datetime(&self) -> Result<chrono::DateTime<chrono_tz::Tz>> {
+        parse_datetime(
+            &self.real_time.last_refreshed,
+            parse_time_zone(&self.real_time.time_zone)?,
+        )
+    }
+
     /// Return time zone of all data time
     #[must_use]
     pub fn time_zone(&self) -> &str {
@@ -67,7 +140,8 @@ impl Exchange {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let exchange = api.exchange("BTC", "CNY").json().await.unwrap();
     ///     let code_from = exchange.code_from();
     ///     assert_eq!(code_from, "BTC");
@@ -83,7 +157,8 @@ impl Exchange {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let exchange = api.exchange("BTC", "CNY").json().await.unwrap();
     ///     let name_from = exchange.name_from();
     ///     assert_eq!(name_from, "Bitcoin");
@@ -99,7 +174,8 @@ impl Exchange {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let exchange = api.exchange("BTC", "CNY").json().await.unwrap();
     ///     let code_to = exchange.code_to();
     ///     assert_eq!(code_to, "CNY");
@@ -115,7 +191,8 @@ impl Exchange {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let exchange = api.exchange("BTC", "CNY").json().await.unwrap();
     ///     let name_to = exchange.name_to();
     ///     assert_eq!(name_to, "Chinese Yuan");
@@ -8,18 +8,19 @@
 //!
 //! [forex]: https://www.alphavantage.co/documentation/#fx
 
-use std::cmp;
 use std::collections::HashMap;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::api::{ApiClient, OutputSize, TimeSeriesInterval};
-use crate::deserialize::from_str;
+use crate::candle::Candle;
+use crate::deserialize::{from_str, meta_value, Price};
+#[cfg(feature = "chrono")]
+use crate::deserialize::{parse_datetime, parse_time_zone};
 use crate::error::{detect_common_helper_error, Error, Result};
-use crate::vec_trait::FindData;
 
 /// Struct used to store metadata value
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 struct MetaData {
     information: String,
     from_symbol: String,
@@ -31,13 +32,16 @@ struct MetaData {
 }
 
 /// Struct to store Data value
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct Data {
     time: String,
-    open: f64,
-    high: f64,
-    low: f64,
-    close: f64,
+    open: Price,
+    high: Price,
+    low: Price,
+    close: Price,
+    #[cfg(feature = "chrono")]
+    #[serde(skip)]
+    time_zone: String,
 }
 
 impl Data {
@@ -47,45 +51,143 @@ impl Data {
         &self.time
     }
 
+    /// Return time as date time in time zone of meta data
+    ///
+    /// # Errors
+    /// Raise error if time or time zone of meta data cannot be parsed
+    #[cfg(feature = "chrono")]
+    pub fn datetime(&self) -> Result<chrono::DateTime<chrono_tz::Tz>> {
+        parse_datetime(&self.time, parse_time_zone(&self.time_zone)?)
+    }
+
     /// Return open value
     #[must_use]
     pub fn open(&self) -> f64 {
-        self.open
+        self.open.value
+    }
+
+    /// Return open value as exact decimal. Return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn open_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.open.decimal
     }
 
     /// Return high value
     #[must_use]
     pub fn high(&self) -> f64 {
-        self.high
+        self.high.value
+    }
+
+    /// Return high value as exact decimal. Return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn high_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.high.decimal
     }
 
     /// Return low value
     #[must_use]
     pub fn low(&self) -> f64 {
-        self.low
+        self.low.value
+    }
+
+    /// Return low value as exact decimal. Return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn low_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.low.decimal
     }
 
     /// Return close value
     #[must_use]
     pub fn close(&self) -> f64 {
-        self.close
+        self.close.value
+    }
+
+    /// Return close value as exact decimal. Return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn close_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.close.decimal
     }
 }
 
 /// Struct to store Forex data after forex API call
-#[derive(Debug, Default)]
+///
+/// Serialized as object with `meta_data` containing `information`,
+/// `from_symbol`, `to_symbol`, `last_refreshed`, `interval`, `output_size` and
+/// `time_zone` and `data` containing list of object with `time`, `open`,
+/// `high`, `low` and `close`
+///
+/// Decoding returns `Error::AlphaVantageInvalidData` if required meta data is
+/// missing
+///
+/// ```
+/// use alpha_vantage::error::Error;
+/// use alpha_vantage::mock::MockClient;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = MockClient::new().response(
+///         "function=FX_WEEKLY",
+///         r#"{"Meta Data": {"1. Information": "Forex Weekly Prices (open, high, low, close)",
+///             "2. From Symbol": "EUR", "3. To Symbol": "USD", "4. Last Refreshed": "2024-01-02"},
+///             "Time Series FX (Weekly)": {}}"#,
+///     );
+///     let api = alpha_vantage::set_api("demo", client);
+///     let error = api
+///         .forex(alpha_vantage::forex::ForexFunction::Weekly, "EUR", "USD")
+///         .json()
+///         .await
+///         .err()
+///         .unwrap();
+///     assert!(matches!(error, Error::AlphaVantageInvalidData(_)));
+///     assert!(error.to_string().contains("Time Zone"));
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(from = "ForexRecord")]
 pub struct Forex {
     meta_data: MetaData,
     data: Vec<Data>,
 }
 
+/// Normalized representation of `Forex` used for sorting data and restoring
+/// time zone of data while deserializing
+#[derive(Deserialize)]
+struct ForexRecord {
+    meta_data: MetaData,
+    data: Vec<Data>,
+}
+
+impl From<ForexRecord> for Forex {
+    fn from(record: ForexRecord) -> Self {
+        let mut data = record.data;
+        #[cfg(feature = "chrono")]
+        for value in &mut data {
+            value.time_zone.clone_from(&record.meta_data.time_zone);
+        }
+        data.sort_by(|first, second| first.time.cmp(&second.time));
+        Self {
+            meta_data: record.meta_data,
+            data,
+        }
+    }
+}
+
 impl Forex {
     /// Return information of data
     ///
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let forex = api
     ///         .forex(alpha_vantage::forex::ForexFunction::IntraDay, "EUR", "USD")
     ///         .interval(alpha_vantage::api::TimeSeriesInterval::FiveMin)
@@ -107,7 +209,8 @@ impl Forex {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let forex = api
     ///         .forex(alpha_vantage::forex::ForexFunction::IntraDay, "EUR", "USD")
     ///         .interval(alpha_vantage::api::TimeSeriesInterval::FiveMin)
@@ -129,7 +232,8 @@ impl Forex {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let forex = api
     ///         .forex(alpha_vantage::forex::ForexFunction::IntraDay, "EUR", "USD")
     ///         .interval(alpha_vantage::api::TimeSeriesInterval::FiveMin)
@@ -163,7 +267,8 @@ impl Forex {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let forex = api
     ///         .forex(alpha_vantage::forex::ForexFunction::IntraDay, "EUR", "USD")
     ///         .interval(alpha_vantage::api::TimeSeriesInterval::FiveMin)
@@ -185,7 +290,8 @@ impl Forex {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let forex = api
     ///         .forex(alpha_vantage::forex::ForexFunction::IntraDay, "EUR", "USD")
     ///         .interval(alpha_vantage::api::TimeSeriesInterval::FiveMin)
@@ -202,7 +308,7 @@ impl Forex {
         self.operate_option_meta_value("output size")
     }
 
-    /// Method return Data
+    /// Method return Data sorted chronologically
     #[must_use]
     pub fn data(&self) -> &Vec<Data> {
         &self.data
@@ -235,13 +341,13 @@ impl Forex {
 #[derive(Clone, Debug, Deserialize)]
 struct DataHelper {
     #[serde(rename = "1. open", deserialize_with = "from_str")]
-    open: f64,
+    open: Price,
     #[serde(rename = "2. high", deserialize_with = "from_str")]
-    high: f64,
+    high: Price,
     #[serde(rename = "3. low", deserialize_with = "from_str")]
-    low: f64,
+    low: Price,
     #[serde(rename = "4. close", deserialize_with = "from_str")]
-    close: f64,
+    close: Price,
 }
 
 /// struct which helps for collecting forex data from website
@@ -260,32 +366,23 @@ pub(crate) struct ForexHelper {
 }
 
 impl ForexHelper {
-    /// convert `ForexHelper` to `Forex`
+    /// convert `ForexHelper` to `Forex`. Return
+    /// `Error::AlphaVantageInvalidData` if required meta data is missing
     fn convert(self) -> Result<Forex> {
         detect_common_helper_error(self.information, self.error_message, self.note)?;
 
-        if self.meta_data.is_none() || self.forex.is_none() {
+        let (Some(meta_data), Some(forex)) = (self.meta_data, self.forex) else {
             return Err(Error::EmptyResponse);
-        }
-
-        let meta_data = self.meta_data.unwrap();
-
-        let information = &meta_data["1. Information"];
-        let from_symbol = &meta_data["2. From Symbol"];
-        let to_symbol = &meta_data["3. To Symbol"];
-
-        let mut last_refreshed = meta_data.get("4. Last Refreshed");
-        if last_refreshed.is_none() {
-            last_refreshed = meta_data.get("5. Last Refreshed");
         };
 
-        let time_zone_value = meta_data.get("5. Time Zone").unwrap_or_else(|| {
-            meta_data.get("6. Time Zone").unwrap_or_else(|| {
-                meta_data
-                    .get("7. Time Zone")
-                    .expect("time zone contains None value")
-            })
-        });
+        let information = meta_value(&meta_data, &["1. Information"])?;
+        let from_symbol = meta_value(&meta_data, &["2. From Symbol"])?;
+        let to_symbol = meta_value(&meta_data, &["3. To Symbol"])?;
+        let last_refreshed = meta_value(&meta_data, &["4. Last Refreshed", "5. Last Refreshed"])?;
+        let time_zone_value = meta_value(
+            &meta_data,
+            &["5. Time Zone", "6. Time Zone", "7. Time Zone"],
+        )?;
 
         let mut output_size_value = meta_data.get("4. Output Size");
         if output_size_value.is_none() {
@@ -295,33 +392,30 @@ impl ForexHelper {
         let interval = meta_data.get("5. Interval");
 
         let meta_data = MetaData {
-            information: information.to_string(),
-            from_symbol: from_symbol.to_string(),
-            to_symbol: to_symbol.to_string(),
-            last_refreshed: last_refreshed
-                .expect("last refreshed value contains None")
-                .to_string(),
+            information: information.clone(),
+            from_symbol: from_symbol.clone(),
+            to_symbol: to_symbol.clone(),
+            last_refreshed: last_refreshed.clone(),
             interval: interval.map(ToString::to_string),
             output_size: output_size_value.map(ToString::to_string),
-            time_zone: time_zone_value.to_string(),
+            time_zone: time_zone_value.clone(),
         };
         let mut data_entries: Vec<Data> = Vec::new();
-        for hash in self.forex.unwrap().values() {
-            for val in hash.keys() {
-                let data_helper = hash
-                    .get(val)
-                    .expect("failed to get value from Forex hashmap");
-
+        for hash in forex.values() {
+            for (val, data_helper) in hash {
                 data_entries.push(Data {
-                    time: val.to_string(),
+                    time: val.clone(),
                     open: data_helper.open,
                     high: data_helper.high,
                     low: data_helper.low,
                     close: data_helper.close,
+                    #[cfg(feature = "chrono")]
+                    time_zone: time_zone_value.clone(),
                 });
             }
         }
 
+        data_entries.sort_by(|first, second| first.time.cmp(&second.time));
         Ok(Forex {
             data: data_entries,
             meta_data,
@@ -329,38 +423,29 @@ impl ForexHelper {
     }
 }
 
-impl FindData for Vec<Data> {
-    #[must_use]
-    fn find(&self, time: &str) -> Option<&<Self as IntoIterator>::Item> {
-        self.iter().find(|&data| data.time == time)
+impl Candle for Data {
+    fn time(&self) -> &str {
+        &self.time
     }
 
-    #[must_use]
-    fn latest(&self) -> <Self as IntoIterator>::Item {
-        let mut latest = &Data::default();
-        for data in self {
-            if latest.time < data.time {
-                latest = data;
-            }
-        }
-        latest.clone()
+    fn open(&self) -> f64 {
+        self.open.value
     }
 
-    fn latest_n(&self, n: usize) -> Result<Vec<&<Self as IntoIterator>::Item>> {
-        let mut time_list = self.iter().map(|data| &data.time).collect::<Vec<_>>();
-        time_list.sort_by_key(|w| cmp::Reverse(*w));
-
-        if n > time_list.len() {
-            return Err(Error::DesiredNumberOfDataNotPresent(time_list.len()));
-        }
+    fn high(&self) -> f64 {
+        self.high.value
+    }
 
-        let mut full_list = Vec::<&Data>::new();
+    fn low(&self) -> f64 {
+        self.low.value
+    }
 
-        for time in &time_list[0..n] {
-            full_list.push(self.find(time).unwrap());
-        }
+    fn close(&self) -> f64 {
+        self.close.value
+    }
 
-        Ok(full_list)
+    fn volume(&self) -> Option<f64> {
+        None
     }
 }
 
@@ -430,15 +515,17 @@ impl<'a> ForexBuilder<'a> {
                 TimeSeriesInterval::ThirtyMin => "30min",
                 TimeSeriesInterval::SixtyMin => "60min",
             };
-            url.push_str(&format!("&interval={interval}"));
-        };
+            url.push_str("&interval=");
+            url.push_str(interval);
+        }
 
         if let Some(forex_output_size) = &self.output_size {
             let size = match forex_output_size {
                 OutputSize::Full => "full",
                 OutputSize::Compact => "compact",
             };
-            url.push_str(&format!("&outputsize={size}"));
+            url.push_str("&outputsize=");
+            url.push_str(size);
         }
 
         url
@@ -13,6 +13,12 @@
 /// requesting through that API
 pub mod api;
 
+pub mod cache;
+
+pub mod calendar;
+
+pub mod candle;
+
 /// Module which provides trait to implement own client as well as default
 /// client in project
 pub mod client;
@@ -34,10 +40,28 @@ pub mod exchange;
 
 pub mod forex;
 
+pub mod listing;
+
+pub mod local_indicator;
+
+pub mod mock;
+
+pub mod news;
+
+pub mod overview;
+
 pub mod quote;
 
+pub mod rate_limit;
+
+pub mod recording;
+
+pub mod retry;
+
 pub mod search;
 
+pub mod statement;
+
 pub mod stock_time;
 
 pub mod technical_indicator;
@@ -45,6 +69,14 @@ pub mod technical_indicator;
 pub mod vec_trait;
 
 pub use self::api::ApiClient;
+#[cfg(feature = "chrono")]
+pub use chrono;
+#[cfg(feature = "chrono")]
+pub use chrono_tz;
+#[cfg(feature = "decimal")]
+pub use rust_decimal;
+#[cfg(feature = "blocking")]
+use self::client::BlockingHttpClient;
 use self::client::HttpClient;
 
 /// Set API key using user selected or created client
@@ -61,6 +93,21 @@ where
     ApiClient::set_api(api, client)
 }
 
+/// Set API key using user selected or created blocking client
+///
+/// ```
+/// let api = alpha_vantage::set_blocking_api("some_key", reqwest::blocking::Client::new());
+/// ```
+#[cfg(feature = "blocking")]
+#[must_use]
+pub fn set_blocking_api<S, T>(api: S, client: T) -> ApiClient
+where
+    S: Into<String>,
+    T: BlockingHttpClient + 'static + Send + Sync,
+{
+    ApiClient::set_blocking_api(api, client)
+}
+
 /// Set Rapid API key using user selected or created client
 ///
 /// ```
@@ -75,20 +122,64 @@ where
     ApiClient::set_rapid_api(api, client)
 }
 
-/// Create json data struct
+/// Create csv data struct from rows of CSV output. Output struct should
+/// implement `From` vector of row
+macro_rules! csv_data_struct {
+    ($output:ident, $row:ident) => {
+        /// Returns data parsed from CSV output
+        ///
+        /// # Errors
+        /// Raise error if data obtained cannot be properly converted to struct or
+        /// API returns any 4 possible known errors
+        pub async fn csv(&self) -> Result<$output> {
+            let url = self.create_url();
+            let rows: Vec<$row> = self.api_client.get_csv(&url).await?;
+            Ok($output::from(rows))
+        }
+
+        /// Returns data parsed from CSV output by blocking current thread.
+        /// `ApiClient` should be created with client which does not require
+        /// async runtime such as blocking client
+        ///
+        /// # Errors
+        /// Raise error if data obtained cannot be properly converted to struct or
+        /// API returns any 4 possible known errors
+        #[cfg(feature = "blocking")]
+        pub fn csv_blocking(&self) -> Result<$output> {
+            futures_executor::block_on(self.csv())
+        }
+    };
+}
+
+/// Create json data struct. Optional validation method of builder is called
+/// before creating url
 macro_rules! json_data_struct {
-    ($output:ident, $helper:ident) => {
+    ($output:ident, $helper:ident $(, $validate:ident)?) => {
         /// Returns JSON data
         ///
         /// # Errors
         /// Raise error if data obtained cannot be properly converted to struct or
         /// API returns any 4 possible known errors
         pub async fn json(&self) -> Result<$output> {
+            $(self.$validate()?;)?
             let url = self.create_url();
             let helper: $helper = self.api_client.get_json(&url).await?;
             helper.convert()
         }
+
+        /// Returns JSON data by blocking current thread. `ApiClient` should be
+        /// created with client which does not require async runtime such as
+        /// blocking client
+        ///
+        /// # Errors
+        /// Raise error if data obtained cannot be properly converted to struct or
+        /// API returns any 4 possible known errors
+        #[cfg(feature = "blocking")]
+        pub fn json_blocking(&self) -> Result<$output> {
+            futures_executor::block_on(self.json())
+        }
     };
 }
 
+pub(crate) use csv_data_struct;
 pub(crate) use json_data_struct;
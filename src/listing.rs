@@ -0,0 +1,199 @@
+//! Module for returning listing and delisting status of symbols
+//!
+//! This API returns a list of active or delisted US stocks and ETFs, either as
+//! of the latest trading day or at a specific time in history.
+//!
+//! You can read about [Listing & Delisting Status][listing_status] API and
+//! what it returns on alphavantage documentation
+//!
+//! [listing_status]: https://www.alphavantage.co/documentation/#listing-status
+
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::ApiClient;
+use crate::deserialize::from_missing_str;
+use crate::error::Result;
+
+/// Enum for declaring listing state of symbol
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ListingState {
+    /// symbol is actively traded
+    #[default]
+    Active,
+    /// symbol is delisted
+    Delisted,
+}
+
+/// Struct to store listing status of single symbol
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ListingEntry {
+    symbol: String,
+    name: String,
+    exchange: String,
+    #[serde(rename(deserialize = "assetType"), alias = "asset_type")]
+    asset_type: String,
+    #[serde(
+        rename(deserialize = "ipoDate"),
+        alias = "ipo_date",
+        deserialize_with = "from_missing_str"
+    )]
+    ipo_date: Option<String>,
+    #[serde(
+        rename(deserialize = "delistingDate"),
+        alias = "delisting_date",
+        deserialize_with = "from_missing_str"
+    )]
+    delisting_date: Option<String>,
+    status: ListingState,
+}
+
+impl ListingEntry {
+    /// Return symbol
+    #[must_use]
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Return name of symbol
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return exchange where symbol is listed
+    #[must_use]
+    pub fn exchange(&self) -> &str {
+        &self.exchange
+    }
+
+    /// Return asset type such as `Stock` or `ETF`
+    #[must_use]
+    pub fn asset_type(&self) -> &str {
+        &self.asset_type
+    }
+
+    /// Return IPO date. Return None if api return null
+    #[must_use]
+    pub fn ipo_date(&self) -> Option<&str> {
+        self.ipo_date.as_deref()
+    }
+
+    /// Return delisting date. Return None if symbol is not delisted
+    #[must_use]
+    pub fn delisting_date(&self) -> Option<&str> {
+        self.delisting_date.as_deref()
+    }
+
+    /// Return listing status
+    #[must_use]
+    pub fn status(&self) -> ListingState {
+        self.status
+    }
+}
+
+/// Struct to store listing status of symbols
+///
+/// Serialized as object with `entries` containing list of object with
+/// `symbol`, `name`, `exchange`, `asset_type`, `ipo_date`, `delisting_date`
+/// and `status`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListingStatus {
+    entries: Vec<ListingEntry>,
+}
+
+impl ListingStatus {
+    /// Return listing status of symbols
+    ///
+    /// # Example
+    /// ```
+    /// use alpha_vantage::listing::ListingState;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let listing_status = api
+    ///         .listing_status()
+    ///         .state(ListingState::Delisted)
+    ///         .csv()
+    ///         .await
+    ///         .unwrap();
+    ///     let entry = &listing_status.entries()[0];
+    ///     assert_eq!(entry.symbol(), "AAC-U");
+    ///     assert_eq!(entry.delisting_date(), Some("2023-11-07"));
+    ///     assert_eq!(entry.status(), ListingState::Delisted);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn entries(&self) -> &Vec<ListingEntry> {
+        &self.entries
+    }
+
+    /// Return true if symbol is present with active status
+    #[must_use]
+    pub fn is_listed(&self, symbol: &str) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| entry.symbol == symbol && entry.status == ListingState::Active)
+    }
+}
+
+impl From<Vec<ListingEntry>> for ListingStatus {
+    fn from(entries: Vec<ListingEntry>) -> Self {
+        Self { entries }
+    }
+}
+
+/// Builder to help create `ListingStatus`
+pub struct ListingStatusBuilder<'a> {
+    api_client: &'a ApiClient,
+    date: Option<&'a str>,
+    state: Option<ListingState>,
+}
+
+impl<'a> ListingStatusBuilder<'a> {
+    crate::csv_data_struct!(ListingStatus, ListingEntry);
+
+    /// Create new `ListingStatusBuilder` with help of `APIClient`
+    #[must_use]
+    pub fn new(api_client: &'a ApiClient) -> Self {
+        Self {
+            api_client,
+            date: None,
+            state: None,
+        }
+    }
+
+    /// Set date in `YYYY-MM-DD` format for which listing status is returned.
+    /// Latest trading day is used if date is not set
+    pub fn date(&mut self, date: &'a str) -> &mut Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Set listing state of returned symbols. Active symbols are returned if
+    /// state is not set
+    pub fn state(&mut self, state: ListingState) -> &mut Self {
+        self.state = Some(state);
+        self
+    }
+
+    fn create_url(&self) -> String {
+        let mut created_link = "query?function=LISTING_STATUS".to_string();
+
+        if let Some(date) = self.date {
+            let _ = write!(created_link, "&date={date}");
+        }
+
+        if let Some(state) = self.state {
+            match state {
+                ListingState::Active => created_link.push_str("&state=active"),
+                ListingState::Delisted => created_link.push_str("&state=delisted"),
+            }
+        }
+
+        created_link
+    }
+}
@@ -0,0 +1,577 @@
+//! Module for computing technical indicator locally
+//!
+//! Technical indicator API uses one API call for each indicator of each
+//! symbol. Functions of this module compute common indicator from already
+//! fetched stock time series, forex or crypto data instead. Output has same
+//! shape and value name as `TechnicalIndicator::data` so result can be
+//! compared against API. Indicator which requires single series uses close
+//! price. Data needs to be sorted chronologically which is the order returned
+//! by `data()` of all series.
+//!
+//! Example of each function uses `Bar` candle which stores time, open, high,
+//! low, close and optional volume in order.
+//!
+//! ```
+//! use alpha_vantage::local_indicator;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+//!     let api = alpha_vantage::set_api("demo", client);
+//!     let stock = api
+//!         .stock_time(alpha_vantage::stock_time::StockFunction::Daily, "IBM")
+//!         .json()
+//!         .await
+//!         .unwrap();
+//!     let data = stock.data();
+//!     let sma = local_indicator::sma(data, 10);
+//!     assert_eq!(sma.len(), data.len() - 9);
+//!     assert_eq!(sma[0].time(), data[9].time());
+//!     let expected = data[..10].iter().map(|value| value.close()).sum::<f64>() / 10.0;
+//!     assert!((sma[0].values()["SMA"] - expected).abs() < 1e-9);
+//! }
+//! ```
+
+use crate::candle::Candle;
+use crate::technical_indicator::DataCollector;
+
+/// Create data collector for time of candle with values
+fn collector<T>(candle: &T, values: &[(&str, f64)]) -> DataCollector
+where
+    T: Candle,
+{
+    DataCollector::new(
+        candle.time(),
+        values
+            .iter()
+            .map(|(key, value)| ((*key).to_string(), *value))
+            .collect(),
+    )
+}
+
+/// Create data collector for all data whose value is present
+fn collect<T>(data: &[T], key: &str, values: &[Option<f64>]) -> Vec<DataCollector>
+where
+    T: Candle,
+{
+    data.iter()
+        .zip(values)
+        .filter_map(|(candle, value)| value.map(|value| collector(candle, &[(key, value)])))
+        .collect()
+}
+
+/// Return close price of all data
+fn closes<T>(data: &[T]) -> Vec<f64>
+where
+    T: Candle,
+{
+    data.iter().map(Candle::close).collect()
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+/// Simple moving average aligned with values
+fn sma_values(values: &[f64], period: usize) -> Vec<Option<f64>> {
+    let mut output = vec![None; values.len()];
+    if period == 0 {
+        return output;
+    }
+    for (index, window) in values.windows(period).enumerate() {
+        output[index + period - 1] = Some(mean(window));
+    }
+    output
+}
+
+/// Exponential moving average aligned with values. First value is seeded with
+/// simple moving average
+#[allow(clippy::cast_precision_loss)]
+fn ema_values(values: &[f64], period: usize) -> Vec<Option<f64>> {
+    let mut output = vec![None; values.len()];
+    if period == 0 || values.len() < period {
+        return output;
+    }
+    let multiplier = 2.0 / (period as f64 + 1.0);
+    let mut ema = mean(&values[..period]);
+    output[period - 1] = Some(ema);
+    for index in period..values.len() {
+        ema += (values[index] - ema) * multiplier;
+        output[index] = Some(ema);
+    }
+    output
+}
+
+/// Weighted moving average aligned with values
+#[allow(clippy::cast_precision_loss)]
+fn wma_values(values: &[f64], period: usize) -> Vec<Option<f64>> {
+    let mut output = vec![None; values.len()];
+    if period == 0 {
+        return output;
+    }
+    let divisor = (period * (period + 1) / 2) as f64;
+    for (index, window) in values.windows(period).enumerate() {
+        let weighted = window
+            .iter()
+            .enumerate()
+            .map(|(weight, value)| (weight + 1) as f64 * value)
+            .sum::<f64>();
+        output[index + period - 1] = Some(weighted / divisor);
+    }
+    output
+}
+
+/// Wilder smoothing aligned with values. First value is simple average
+#[allow(clippy::cast_precision_loss)]
+fn wilder_values(values: &[f64], period: usize) -> Vec<Option<f64>> {
+    let mut output = vec![None; values.len()];
+    if period == 0 || values.len() < period {
+        return output;
+    }
+    let mut average = mean(&values[..period]);
+    output[period - 1] = Some(average);
+    for index in period..values.len() {
+        average = (average * (period - 1) as f64 + values[index]) / period as f64;
+        output[index] = Some(average);
+    }
+    output
+}
+
+/// Apply average function to part of values which is present and align output
+/// with values
+fn shifted(
+    values: &[Option<f64>],
+    period: usize,
+    average: fn(&[f64], usize) -> Vec<Option<f64>>,
+) -> Vec<Option<f64>> {
+    let start = values
+        .iter()
+        .position(Option::is_some)
+        .unwrap_or(values.len());
+    let present = values[start..]
+        .iter()
+        .flatten()
+        .copied()
+        .collect::<Vec<_>>();
+    let mut output = vec![None; start];
+    output.extend(average(&present, period));
+    output.resize(values.len(), None);
+    output
+}
+
+/// Compute simple moving average (SMA) of close price. Value is stored with
+/// key `SMA`
+#[must_use]
+pub fn sma<T>(data: &[T], time_period: usize) -> Vec<DataCollector>
+where
+    T: Candle,
+{
+    collect(data, "SMA", &sma_values(&closes(data), time_period))
+}
+
+/// Compute exponential moving average (EMA) of close price. Value is stored
+/// with key `EMA`
+///
+/// ```
+/// use alpha_vantage::local_indicator;
+/// # #[path = "../tests/fixtures/bar.rs"]
+/// # mod bar;
+/// # use bar::Bar;
+///
+/// let data = [
+///     Bar("2024-01-01", 1.0, 1.0, 1.0, 1.0, Some(0.0)),
+///     Bar("2024-01-02", 2.0, 2.0, 2.0, 2.0, Some(0.0)),
+///     Bar("2024-01-03", 3.0, 3.0, 3.0, 3.0, Some(0.0)),
+///     Bar("2024-01-04", 4.0, 4.0, 4.0, 4.0, Some(0.0)),
+///     Bar("2024-01-05", 5.0, 5.0, 5.0, 5.0, Some(0.0)),
+/// ];
+/// let output = local_indicator::ema(&data, 3);
+/// let times = output.iter().map(|value| value.time()).collect::<Vec<_>>();
+/// assert_eq!(times, ["2024-01-03", "2024-01-04", "2024-01-05"]);
+/// let values = output
+///     .iter()
+///     .map(|value| value.values()["EMA"])
+///     .collect::<Vec<_>>();
+/// assert_eq!(values, [2.0, 3.0, 4.0]);
+/// ```
+#[must_use]
+pub fn ema<T>(data: &[T], time_period: usize) -> Vec<DataCollector>
+where
+    T: Candle,
+{
+    collect(data, "EMA", &ema_values(&closes(data), time_period))
+}
+
+/// Compute weighted moving average (WMA) of close price. Value is stored with
+/// key `WMA`
+///
+/// ```
+/// use alpha_vantage::local_indicator;
+/// # #[path = "../tests/fixtures/bar.rs"]
+/// # mod bar;
+/// # use bar::Bar;
+///
+/// let data = [
+///     Bar("2024-01-01", 6.0, 6.0, 6.0, 6.0, Some(0.0)),
+///     Bar("2024-01-02", 3.0, 3.0, 3.0, 3.0, Some(0.0)),
+///     Bar("2024-01-03", 6.0, 6.0, 6.0, 6.0, Some(0.0)),
+///     Bar("2024-01-04", 9.0, 9.0, 9.0, 9.0, Some(0.0)),
+/// ];
+/// let output = local_indicator::wma(&data, 3);
+/// let times = output.iter().map(|value| value.time()).collect::<Vec<_>>();
+/// assert_eq!(times, ["2024-01-03", "2024-01-04"]);
+/// let values = output
+///     .iter()
+///     .map(|value| value.values()["WMA"])
+///     .collect::<Vec<_>>();
+/// assert_eq!(values, [5.0, 7.0]);
+/// ```
+#[must_use]
+pub fn wma<T>(data: &[T], time_period: usize) -> Vec<DataCollector>
+where
+    T: Candle,
+{
+    collect(data, "WMA", &wma_values(&closes(data), time_period))
+}
+
+/// Compute relative strength index (RSI) of close price using Wilder
+/// smoothing. Value is stored with key `RSI`
+///
+/// ```
+/// use alpha_vantage::local_indicator;
+/// # #[path = "../tests/fixtures/bar.rs"]
+/// # mod bar;
+/// # use bar::Bar;
+///
+/// let data = [
+///     Bar("2024-01-01", 1.0, 1.0, 1.0, 1.0, Some(0.0)),
+///     Bar("2024-01-02", 2.0, 2.0, 2.0, 2.0, Some(0.0)),
+///     Bar("2024-01-03", 3.0, 3.0, 3.0, 3.0, Some(0.0)),
+///     Bar("2024-01-04", 2.0, 2.0, 2.0, 2.0, Some(0.0)),
+///     Bar("2024-01-05", 3.0, 3.0, 3.0, 3.0, Some(0.0)),
+/// ];
+/// let output = local_indicator::rsi(&data, 2);
+/// let times = output.iter().map(|value| value.time()).collect::<Vec<_>>();
+/// assert_eq!(times, ["2024-01-03", "2024-01-04", "2024-01-05"]);
+/// let values = output
+///     .iter()
+///     .map(|value| value.values()["RSI"])
+///     .collect::<Vec<_>>();
+/// assert_eq!(values, [100.0, 50.0, 75.0]);
+/// ```
+#[must_use]
+pub fn rsi<T>(data: &[T], time_period: usize) -> Vec<DataCollector>
+where
+    T: Candle,
+{
+    let changes = closes(data)
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .collect::<Vec<_>>();
+    let gains = changes
+        .iter()
+        .map(|change| change.max(0.0))
+        .collect::<Vec<_>>();
+    let losses = changes
+        .iter()
+        .map(|change| (-change).max(0.0))
+        .collect::<Vec<_>>();
+    let average_gain = wilder_values(&gains, time_period);
+    let average_loss = wilder_values(&losses, time_period);
+    let mut values = vec![None];
+    values.extend(average_gain.iter().zip(&average_loss).map(|(gain, loss)| {
+        let (gain, loss) = (gain.as_ref()?, loss.as_ref()?);
+        if *loss == 0.0 {
+            Some(100.0)
+        } else {
+            Some(100.0 - 100.0 / (1.0 + gain / loss))
+        }
+    }));
+    values.truncate(data.len());
+    collect(data, "RSI", &values)
+}
+
+/// Compute moving average convergence divergence (MACD) of close price.
+/// Values are stored with key `MACD`, `MACD_Signal` and `MACD_Hist`
+///
+/// ```
+/// use alpha_vantage::local_indicator;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+///     let api = alpha_vantage::set_api("demo", client);
+///     let stock = api
+///         .stock_time(alpha_vantage::stock_time::StockFunction::Daily, "IBM")
+///         .json()
+///         .await
+///         .unwrap();
+///     let macd = local_indicator::macd(stock.data(), 12, 26, 9);
+///     assert_eq!(macd.len(), stock.data().len() - 33);
+///     let values = macd[0].values();
+///     assert!((values["MACD"] - values["MACD_Signal"] - values["MACD_Hist"]).abs() < 1e-9);
+/// }
+/// ```
+#[must_use]
+pub fn macd<T>(
+    data: &[T],
+    fast_period: usize,
+    slow_period: usize,
+    signal_period: usize,
+) -> Vec<DataCollector>
+where
+    T: Candle,
+{
+    let closes = closes(data);
+    let fast = ema_values(&closes, fast_period);
+    let slow = ema_values(&closes, slow_period);
+    let macd = fast
+        .iter()
+        .zip(&slow)
+        .map(|(fast, slow)| Some(fast.as_ref()? - slow.as_ref()?))
+        .collect::<Vec<_>>();
+    let signal = shifted(&macd, signal_period, ema_values);
+    data.iter()
+        .zip(macd.iter().zip(&signal))
+        .filter_map(|(candle, (macd, signal))| {
+            let (macd, signal) = ((*macd)?, (*signal)?);
+            Some(collector(
+                candle,
+                &[
+                    ("MACD", macd),
+                    ("MACD_Signal", signal),
+                    ("MACD_Hist", macd - signal),
+                ],
+            ))
+        })
+        .collect()
+}
+
+/// Compute Bollinger bands of close price using simple moving average and
+/// population standard deviation. Values are stored with key `Real Upper
+/// Band`, `Real Middle Band` and `Real Lower Band`. Returns empty vector if
+/// time period is zero or greater than number of data
+///
+/// ```
+/// use alpha_vantage::local_indicator;
+/// # #[path = "../tests/fixtures/bar.rs"]
+/// # mod bar;
+/// # use bar::Bar;
+///
+/// let data = [
+///     Bar("2024-01-01", 1.0, 1.0, 1.0, 1.0, Some(0.0)),
+///     Bar("2024-01-02", 3.0, 3.0, 3.0, 3.0, Some(0.0)),
+///     Bar("2024-01-03", 5.0, 5.0, 5.0, 5.0, Some(0.0)),
+/// ];
+/// let output = local_indicator::bbands(&data, 2, 2.0);
+/// assert_eq!(output.len(), 2);
+/// assert_eq!(output[0].time(), "2024-01-02");
+/// assert_eq!(output[0].values()["Real Upper Band"], 4.0);
+/// assert_eq!(output[0].values()["Real Middle Band"], 2.0);
+/// assert_eq!(output[0].values()["Real Lower Band"], 0.0);
+/// assert_eq!(output[1].values()["Real Upper Band"], 6.0);
+/// assert_eq!(output[1].values()["Real Middle Band"], 4.0);
+/// assert_eq!(output[1].values()["Real Lower Band"], 2.0);
+///
+/// assert!(local_indicator::bbands(&data, 0, 2.0).is_empty());
+/// assert!(local_indicator::bbands(&data, 5, 2.0).is_empty());
+/// ```
+#[must_use]
+pub fn bbands<T>(data: &[T], time_period: usize, deviation: f64) -> Vec<DataCollector>
+where
+    T: Candle,
+{
+    if time_period == 0 || data.len() < time_period {
+        return Vec::new();
+    }
+    closes(data)
+        .windows(time_period)
+        .zip(&data[time_period - 1..])
+        .map(|(window, candle)| {
+            let middle = mean(window);
+            let variance = mean(
+                &window
+                    .iter()
+                    .map(|value| (value - middle).powi(2))
+                    .collect::<Vec<_>>(),
+            );
+            let band = deviation * variance.sqrt();
+            collector(
+                candle,
+                &[
+                    ("Real Upper Band", middle + band),
+                    ("Real Middle Band", middle),
+                    ("Real Lower Band", middle - band),
+                ],
+            )
+        })
+        .collect()
+}
+
+/// Compute average true range (ATR) using Wilder smoothing. Value is stored
+/// with key `ATR`
+///
+/// ```
+/// use alpha_vantage::local_indicator;
+/// # #[path = "../tests/fixtures/bar.rs"]
+/// # mod bar;
+/// # use bar::Bar;
+///
+/// let data = [
+///     Bar("2024-01-01", 1.5, 2.0, 1.0, 1.5, Some(0.0)),
+///     Bar("2024-01-02", 2.5, 3.0, 2.0, 2.5, Some(0.0)),
+///     Bar("2024-01-03", 2.0, 3.0, 1.0, 2.0, Some(0.0)),
+///     Bar("2024-01-04", 4.0, 5.0, 3.0, 4.0, Some(0.0)),
+/// ];
+/// let output = local_indicator::atr(&data, 2);
+/// let times = output.iter().map(|value| value.time()).collect::<Vec<_>>();
+/// assert_eq!(times, ["2024-01-03", "2024-01-04"]);
+/// let values = output
+///     .iter()
+///     .map(|value| value.values()["ATR"])
+///     .collect::<Vec<_>>();
+/// assert_eq!(values, [1.75, 2.375]);
+/// ```
+#[must_use]
+pub fn atr<T>(data: &[T], time_period: usize) -> Vec<DataCollector>
+where
+    T: Candle,
+{
+    let true_range = data
+        .windows(2)
+        .map(|pair| {
+            let previous_close = pair[0].close();
+            let (high, low) = (pair[1].high(), pair[1].low());
+            (high - low)
+                .max((high - previous_close).abs())
+                .max((low - previous_close).abs())
+        })
+        .collect::<Vec<_>>();
+    let mut values = vec![None];
+    values.extend(wilder_values(&true_range, time_period));
+    values.truncate(data.len());
+    collect(data, "ATR", &values)
+}
+
+/// Compute stochastic oscillator. Values are stored with key `SlowK` and
+/// `SlowD`
+///
+/// ```
+/// use alpha_vantage::local_indicator;
+/// # #[path = "../tests/fixtures/bar.rs"]
+/// # mod bar;
+/// # use bar::Bar;
+///
+/// let data = [
+///     Bar("2024-01-01", 1.5, 2.0, 1.0, 1.5, Some(0.0)),
+///     Bar("2024-01-02", 2.5, 3.0, 2.0, 2.5, Some(0.0)),
+///     Bar("2024-01-03", 2.0, 3.0, 1.0, 2.0, Some(0.0)),
+///     Bar("2024-01-04", 4.0, 5.0, 3.0, 4.0, Some(0.0)),
+/// ];
+/// let output = local_indicator::stoch(&data, 2, 1, 2);
+/// assert_eq!(output.len(), 2);
+/// assert_eq!(output[0].time(), "2024-01-03");
+/// assert_eq!(output[0].values()["SlowK"], 50.0);
+/// assert_eq!(output[0].values()["SlowD"], 62.5);
+/// assert_eq!(output[1].time(), "2024-01-04");
+/// assert_eq!(output[1].values()["SlowK"], 75.0);
+/// assert_eq!(output[1].values()["SlowD"], 62.5);
+/// ```
+#[must_use]
+#[allow(clippy::similar_names)]
+pub fn stoch<T>(
+    data: &[T],
+    fastk_period: usize,
+    slowk_period: usize,
+    slowd_period: usize,
+) -> Vec<DataCollector>
+where
+    T: Candle,
+{
+    let mut fast_k = vec![None; data.len()];
+    if fastk_period > 0 {
+        for (index, window) in data.windows(fastk_period).enumerate() {
+            let highest = window
+                .iter()
+                .map(Candle::high)
+                .fold(f64::NEG_INFINITY, f64::max);
+            let lowest = window.iter().map(Candle::low).fold(f64::INFINITY, f64::min);
+            let close = window[fastk_period - 1].close();
+            let value = if highest > lowest {
+                100.0 * (close - lowest) / (highest - lowest)
+            } else {
+                0.0
+            };
+            fast_k[index + fastk_period - 1] = Some(value);
+        }
+    }
+    let slow_k = shifted(&fast_k, slowk_period, sma_values);
+    let slow_d = shifted(&slow_k, slowd_period, sma_values);
+    data.iter()
+        .zip(slow_k.iter().zip(&slow_d))
+        .filter_map(|(candle, (slow_k, slow_d))| {
+            Some(collector(
+                candle,
+                &[("SlowK", (*slow_k)?), ("SlowD", (*slow_d)?)],
+            ))
+        })
+        .collect()
+}
+
+/// Compute on balance volume (OBV). Value is stored with key `OBV`. Return
+/// empty output if any data does not have volume such as forex data
+///
+/// ```
+/// use alpha_vantage::local_indicator;
+/// # #[path = "../tests/fixtures/bar.rs"]
+/// # mod bar;
+/// # use bar::Bar;
+///
+/// let data = [
+///     Bar("2024-01-01", 1.0, 1.0, 1.0, 1.0, Some(10.0)),
+///     Bar("2024-01-02", 2.0, 2.0, 2.0, 2.0, Some(20.0)),
+///     Bar("2024-01-03", 2.0, 2.0, 2.0, 2.0, Some(30.0)),
+///     Bar("2024-01-04", 1.0, 1.0, 1.0, 1.0, Some(40.0)),
+/// ];
+/// let output = local_indicator::obv(&data);
+/// let times = output.iter().map(|value| value.time()).collect::<Vec<_>>();
+/// assert_eq!(
+///     times,
+///     ["2024-01-01", "2024-01-02", "2024-01-03", "2024-01-04"]
+/// );
+/// let values = output
+///     .iter()
+///     .map(|value| value.values()["OBV"])
+///     .collect::<Vec<_>>();
+/// assert_eq!(values, [10.0, 30.0, 30.0, -10.0]);
+///
+/// let data = [
+///     Bar("2024-01-01", 1.0, 1.0, 1.0, 1.0, Some(10.0)),
+///     Bar("2024-01-02", 2.0, 2.0, 2.0, 2.0, None),
+/// ];
+/// assert!(local_indicator::obv(&data).is_empty());
+/// ```
+#[must_use]
+pub fn obv<T>(data: &[T]) -> Vec<DataCollector>
+where
+    T: Candle,
+{
+    let mut output = Vec::with_capacity(data.len());
+    let mut total = 0.0;
+    let mut previous_close = None;
+    for candle in data {
+        let Some(volume) = candle.volume() else {
+            return Vec::new();
+        };
+        match previous_close {
+            None => total = volume,
+            Some(previous) if candle.close() > previous => total += volume,
+            Some(previous) if candle.close() < previous => total -= volume,
+            Some(_) => {}
+        }
+        previous_close = Some(candle.close());
+        output.push(collector(candle, &[("OBV", total)]));
+    }
+    output
+}
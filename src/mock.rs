@@ -0,0 +1,174 @@
+//! Module for offline mock client
+//!
+//! `MockClient` implements `HttpClient` and serves canned response instead of
+//! calling API. Response is selected by matching query parameter of request
+//! (function, symbol, interval, etc.) so crate can be used without network
+//! access in test.
+
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use async_trait::async_trait;
+
+use crate::client::HttpClient;
+use crate::error::{Error, Result};
+
+/// Query parameter which are ignored while matching request
+const IGNORED_PARAMS: [&str; 1] = ["apikey"];
+
+/// Parse query parameter present in url or path
+pub(crate) fn query_params(path: &str) -> Vec<(String, String)> {
+    let query = path.split_once('?').map_or(path, |(_, query)| query);
+    query
+        .split('&')
+        .filter(|param| !param.is_empty())
+        .map(|param| {
+            let (key, value) = param.split_once('=').unwrap_or((param, ""));
+            (key.to_string(), value.to_string())
+        })
+        .filter(|(key, _)| !IGNORED_PARAMS.contains(&key.as_str()))
+        .collect()
+}
+
+/// Mock client which serves canned response for request
+///
+/// A response is registered with query parameter which needs to be present in
+/// request for it to match. If multiple response matches request, the one with
+/// most number of query parameter is used. API key present in request is
+/// always ignored. Cloned mock client shares recorded unmatched request with
+/// original client.
+///
+/// ```
+/// use alpha_vantage::mock::MockClient;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = MockClient::new().response(
+///         "function=SYMBOL_SEARCH&keywords=BA",
+///         r#"{"bestMatches": []}"#,
+///     );
+///     let api = alpha_vantage::set_api("demo", client);
+///     let search = api.search("BA").json().await.unwrap();
+///     assert!(search.matches().is_empty());
+/// }
+/// ```
+#[derive(Default, Clone)]
+pub struct MockClient {
+    responses: Vec<(Vec<(String, String)>, String)>,
+    unmatched: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockClient {
+    /// Create new mock client without any response
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create new mock client which loads response from fixture directory.
+    /// Each `.json` and `.csv` file inside directory is used as response for
+    /// request whose query parameter matches file name without extension. For
+    /// example `function=GLOBAL_QUOTE&symbol=MSFT.json` file is used as
+    /// response of quote request for MSFT symbol
+    ///
+    /// ```
+    /// let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if directory or a fixture file cannot be read
+    pub fn from_directory<P>(directory: P) -> std::io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let mut client = Self::new();
+        for entry in fs::read_dir(directory)? {
+            let path = entry?.path();
+            if !matches!(
+                path.extension().and_then(|extension| extension.to_str()),
+                Some("json" | "csv")
+            ) {
+                continue;
+            }
+            if let Some(query) = path.file_stem().and_then(|stem| stem.to_str()) {
+                let body = fs::read_to_string(&path)?;
+                client = client.response(query, body);
+            }
+        }
+        Ok(client)
+    }
+
+    /// Add response which is returned for request containing all query
+    /// parameter of query
+    #[must_use]
+    pub fn response<Q, B>(mut self, query: Q, body: B) -> Self
+    where
+        Q: AsRef<str>,
+        B: Into<String>,
+    {
+        self.responses
+            .push((query_params(query.as_ref()), body.into()));
+        self
+    }
+
+    /// Return list of request query for which no response was present. API key
+    /// is removed from returned query
+    ///
+    /// ```
+    /// use alpha_vantage::mock::MockClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = MockClient::new();
+    ///     let api = alpha_vantage::set_api("demo", client.clone());
+    ///     assert!(api.quote("MSFT").json().await.is_err());
+    ///     assert_eq!(
+    ///         client.unmatched_requests(),
+    ///         vec!["function=GLOBAL_QUOTE&symbol=MSFT"]
+    ///     );
+    /// }
+    /// ```
+    #[must_use]
+    pub fn unmatched_requests(&self) -> Vec<String> {
+        self.unmatched
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Return response matching path or record path as unmatched and return
+    /// `Error::MockResponseMissing`
+    fn output(&self, path: &str) -> Result<String> {
+        let params = query_params(path);
+        let matched = self
+            .responses
+            .iter()
+            .filter(|(query, _)| query.iter().all(|param| params.contains(param)))
+            .max_by_key(|(query, _)| query.len());
+        if let Some((_, body)) = matched {
+            return Ok(body.clone());
+        }
+        let query = params
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>()
+            .join("&");
+        self.unmatched
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(query.clone());
+        Err(Error::MockResponseMissing(query))
+    }
+}
+
+#[async_trait]
+impl HttpClient for MockClient {
+    async fn get_alpha_vantage_provider_output(&self, path: &str) -> Result<String> {
+        self.output(path)
+    }
+
+    async fn get_rapid_api_provider_output(&self, path: &str, _api_key: &str) -> Result<String> {
+        self.output(path)
+    }
+}
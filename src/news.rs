@@ -0,0 +1,507 @@
+//! Module for returning market news and sentiment
+//!
+//! This API returns live and historical market news & sentiment data from a
+//! large & growing selection of premier news outlets around the world,
+//! covering stocks, cryptocurrencies, forex, and a wide range of topics such as
+//! fiscal policy, mergers & acquisitions, IPOs, etc.
+//!
+//! You can read about [News & Sentiment][news_sentiment] API and what it
+//! returns on alphavantage documentation
+//!
+//! [news_sentiment]: https://www.alphavantage.co/documentation/#news-sentiment
+
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::ApiClient;
+use crate::deserialize::from_str;
+use crate::error::{detect_common_helper_error, Error, Result};
+
+/// Struct to store topic of article along with its relevance score
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Topic {
+    topic: String,
+    #[serde(deserialize_with = "from_str")]
+    relevance_score: f64,
+}
+
+impl Topic {
+    /// Return name of topic
+    #[must_use]
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    /// Return relevance score of topic for article
+    #[must_use]
+    pub fn relevance_score(&self) -> f64 {
+        self.relevance_score
+    }
+}
+
+/// Struct to store sentiment of article for ticker
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct TickerSentiment {
+    ticker: String,
+    #[serde(deserialize_with = "from_str")]
+    relevance_score: f64,
+    #[serde(
+        rename(deserialize = "ticker_sentiment_score"),
+        alias = "sentiment_score",
+        deserialize_with = "from_str"
+    )]
+    sentiment_score: f64,
+    #[serde(
+        rename(deserialize = "ticker_sentiment_label"),
+        alias = "sentiment_label"
+    )]
+    sentiment_label: String,
+}
+
+impl TickerSentiment {
+    /// Return ticker
+    #[must_use]
+    pub fn ticker(&self) -> &str {
+        &self.ticker
+    }
+
+    /// Return relevance score of ticker for article
+    #[must_use]
+    pub fn relevance_score(&self) -> f64 {
+        self.relevance_score
+    }
+
+    /// Return sentiment score of article for ticker
+    #[must_use]
+    pub fn sentiment_score(&self) -> f64 {
+        self.sentiment_score
+    }
+
+    /// Return sentiment label of article for ticker such as `Bullish` or
+    /// `Somewhat-Bearish`
+    #[must_use]
+    pub fn sentiment_label(&self) -> &str {
+        &self.sentiment_label
+    }
+}
+
+/// Struct to store news article along with its sentiment
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Article {
+    title: String,
+    url: String,
+    time_published: String,
+    authors: Vec<String>,
+    summary: String,
+    banner_image: Option<String>,
+    source: String,
+    category_within_source: String,
+    source_domain: String,
+    topics: Vec<Topic>,
+    #[serde(deserialize_with = "from_str")]
+    overall_sentiment_score: f64,
+    overall_sentiment_label: String,
+    ticker_sentiment: Vec<TickerSentiment>,
+}
+
+impl Article {
+    /// Return title of article
+    #[must_use]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Return url of article
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Return time when article was published in `YYYYMMDDTHHMMSS` format
+    #[must_use]
+    pub fn time_published(&self) -> &str {
+        &self.time_published
+    }
+
+    /// Return time when article was published as naive date time
+    ///
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let news = api.news_sentiment().tickers(&["IBM"]).json().await.unwrap();
+    ///     let time = news.feed()[0].published_datetime().unwrap();
+    ///     assert_eq!(time.to_string(), "2023-10-18 14:30:00");
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if time published cannot be parsed
+    #[cfg(feature = "chrono")]
+    pub fn published_datetime(&self) -> Result<chrono::NaiveDateTime> {
+        chrono::NaiveDateTime::parse_from_str(&self.time_published, "%Y%m%dT%H%M%S").map_err(
+            |err| {
+                Error::AlphaVantageInvalidData(format!(
+                    "invalid time published {}: {err}",
+                    self.time_published
+                ))
+            },
+        )
+    }
+
+    /// Return authors of article
+    #[must_use]
+    pub fn authors(&self) -> &Vec<String> {
+        &self.authors
+    }
+
+    /// Return summary of article
+    #[must_use]
+    pub fn summary(&self) -> &str {
+        &self.summary
+    }
+
+    /// Return url of banner image. Return None if article does not have banner
+    /// image
+    #[must_use]
+    pub fn banner_image(&self) -> Option<&str> {
+        self.banner_image.as_deref()
+    }
+
+    /// Return name of source which published article
+    #[must_use]
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Return category of article within source
+    #[must_use]
+    pub fn category_within_source(&self) -> &str {
+        &self.category_within_source
+    }
+
+    /// Return domain of source
+    #[must_use]
+    pub fn source_domain(&self) -> &str {
+        &self.source_domain
+    }
+
+    /// Return topics of article along with relevance score
+    #[must_use]
+    pub fn topics(&self) -> &Vec<Topic> {
+        &self.topics
+    }
+
+    /// Return overall sentiment score of article
+    #[must_use]
+    pub fn overall_sentiment_score(&self) -> f64 {
+        self.overall_sentiment_score
+    }
+
+    /// Return overall sentiment label of article such as `Bullish` or
+    /// `Somewhat-Bearish`
+    #[must_use]
+    pub fn overall_sentiment_label(&self) -> &str {
+        &self.overall_sentiment_label
+    }
+
+    /// Return sentiment of article for each ticker mentioned in article
+    ///
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let news = api.news_sentiment().tickers(&["IBM"]).json().await.unwrap();
+    ///     let sentiment = &news.feed()[0].ticker_sentiment()[0];
+    ///     assert_eq!(sentiment.ticker(), "IBM");
+    ///     assert_eq!(sentiment.sentiment_score(), 0.356782);
+    ///     assert_eq!(sentiment.sentiment_label(), "Bullish");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn ticker_sentiment(&self) -> &Vec<TickerSentiment> {
+        &self.ticker_sentiment
+    }
+}
+
+/// Struct to store news feed
+///
+/// Serialized as object with `items`, `sentiment_score_definition`,
+/// `relevance_score_definition` and `feed` containing list of article object
+/// with `title`, `url`, `time_published`, `authors`, `summary`,
+/// `banner_image`, `source`, `category_within_source`, `source_domain`,
+/// `topics`, `overall_sentiment_score`, `overall_sentiment_label` and
+/// `ticker_sentiment`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NewsFeed {
+    #[serde(deserialize_with = "from_str")]
+    items: u64,
+    sentiment_score_definition: String,
+    relevance_score_definition: String,
+    feed: Vec<Article>,
+}
+
+impl NewsFeed {
+    /// Return number of article in feed
+    #[must_use]
+    pub fn items(&self) -> u64 {
+        self.items
+    }
+
+    /// Return definition of sentiment score
+    #[must_use]
+    pub fn sentiment_score_definition(&self) -> &str {
+        &self.sentiment_score_definition
+    }
+
+    /// Return definition of relevance score
+    #[must_use]
+    pub fn relevance_score_definition(&self) -> &str {
+        &self.relevance_score_definition
+    }
+
+    /// Return articles of feed
+    ///
+    /// # Example
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let news = api.news_sentiment().tickers(&["IBM"]).json().await.unwrap();
+    ///     let article = &news.feed()[1];
+    ///     assert_eq!(article.source(), "Example Markets");
+    ///     assert_eq!(article.banner_image(), None);
+    ///     assert_eq!(article.topics()[0].topic(), "Earnings");
+    ///     assert_eq!(article.overall_sentiment_label(), "Somewhat-Bearish");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn feed(&self) -> &Vec<Article> {
+        &self.feed
+    }
+}
+
+/// Struct used for creating news feed
+#[derive(Debug, Deserialize)]
+pub(crate) struct NewsFeedHelper {
+    #[serde(rename = "Information")]
+    information: Option<String>,
+    #[serde(rename = "Error Message")]
+    error_message: Option<String>,
+    #[serde(rename = "Note")]
+    note: Option<String>,
+    #[serde(default, deserialize_with = "from_str")]
+    items: u64,
+    sentiment_score_definition: Option<String>,
+    relevance_score_definition: Option<String>,
+    feed: Option<Vec<Article>>,
+}
+
+impl NewsFeedHelper {
+    /// Function which convert `NewsFeedHelper` to `NewsFeed`
+    fn convert(self) -> Result<NewsFeed> {
+        detect_common_helper_error(self.information, self.error_message, self.note)?;
+        let Some(feed) = self.feed else {
+            return Err(Error::EmptyResponse);
+        };
+        Ok(NewsFeed {
+            items: self.items,
+            sentiment_score_definition: self.sentiment_score_definition.unwrap_or_default(),
+            relevance_score_definition: self.relevance_score_definition.unwrap_or_default(),
+            feed,
+        })
+    }
+}
+
+/// Enum for declaring topic of news
+#[derive(Clone, Copy)]
+pub enum NewsTopic {
+    /// blockchain
+    Blockchain,
+    /// earnings
+    Earnings,
+    /// IPO
+    Ipo,
+    /// mergers and acquisitions
+    MergersAndAcquisitions,
+    /// financial markets
+    FinancialMarkets,
+    /// economy - fiscal policy
+    EconomyFiscal,
+    /// economy - monetary policy
+    EconomyMonetary,
+    /// economy - macro
+    EconomyMacro,
+    /// energy and transportation
+    EnergyTransportation,
+    /// finance
+    Finance,
+    /// life sciences
+    LifeSciences,
+    /// manufacturing
+    Manufacturing,
+    /// real estate and construction
+    RealEstate,
+    /// retail and wholesale
+    RetailWholesale,
+    /// technology
+    Technology,
+}
+
+impl NewsTopic {
+    fn as_str(self) -> &'static str {
+        match self {
+            NewsTopic::Blockchain => "blockchain",
+            NewsTopic::Earnings => "earnings",
+            NewsTopic::Ipo => "ipo",
+            NewsTopic::MergersAndAcquisitions => "mergers_and_acquisitions",
+            NewsTopic::FinancialMarkets => "financial_markets",
+            NewsTopic::EconomyFiscal => "economy_fiscal",
+            NewsTopic::EconomyMonetary => "economy_monetary",
+            NewsTopic::EconomyMacro => "economy_macro",
+            NewsTopic::EnergyTransportation => "energy_transportation",
+            NewsTopic::Finance => "finance",
+            NewsTopic::LifeSciences => "life_sciences",
+            NewsTopic::Manufacturing => "manufacturing",
+            NewsTopic::RealEstate => "real_estate",
+            NewsTopic::RetailWholesale => "retail_wholesale",
+            NewsTopic::Technology => "technology",
+        }
+    }
+}
+
+/// Enum for declaring sort order of news
+#[derive(Clone, Copy)]
+pub enum NewsSort {
+    /// latest article first
+    Latest,
+    /// earliest article first
+    Earliest,
+    /// most relevant article first
+    Relevance,
+}
+
+/// Maximum number of article which can be returned by API
+const MAX_LIMIT: u16 = 1000;
+
+/// Builder to help create `NewsFeed`
+pub struct NewsSentimentBuilder<'a> {
+    api_client: &'a ApiClient,
+    tickers: Vec<&'a str>,
+    topics: Vec<NewsTopic>,
+    time_from: Option<&'a str>,
+    time_to: Option<&'a str>,
+    sort: Option<NewsSort>,
+    limit: Option<u16>,
+}
+
+impl<'a> NewsSentimentBuilder<'a> {
+    crate::json_data_struct!(NewsFeed, NewsFeedHelper, validate);
+
+    /// Create new `NewsSentimentBuilder` with help of `APIClient`
+    #[must_use]
+    pub fn new(api_client: &'a ApiClient) -> Self {
+        Self {
+            api_client,
+            tickers: Vec::new(),
+            topics: Vec::new(),
+            time_from: None,
+            time_to: None,
+            sort: None,
+            limit: None,
+        }
+    }
+
+    /// Set tickers mentioned in article. Crypto and forex tickers are prefixed
+    /// with `CRYPTO:` and `FOREX:` such as `CRYPTO:BTC` or `FOREX:USD`
+    pub fn tickers(&mut self, tickers: &[&'a str]) -> &mut Self {
+        self.tickers = tickers.to_vec();
+        self
+    }
+
+    /// Set topics covered by article
+    pub fn topics(&mut self, topics: &[NewsTopic]) -> &mut Self {
+        self.topics = topics.to_vec();
+        self
+    }
+
+    /// Set time in `YYYYMMDDTHHMM` format after which article is published
+    pub fn time_from(&mut self, time_from: &'a str) -> &mut Self {
+        self.time_from = Some(time_from);
+        self
+    }
+
+    /// Set time in `YYYYMMDDTHHMM` format before which article is published
+    pub fn time_to(&mut self, time_to: &'a str) -> &mut Self {
+        self.time_to = Some(time_to);
+        self
+    }
+
+    /// Set sort order of article
+    pub fn sort(&mut self, sort: NewsSort) -> &mut Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Set maximum number of article returned. Limit should be between 1 and
+    /// 1000
+    pub fn limit(&mut self, limit: u16) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        if let Some(limit) = self.limit {
+            if limit == 0 || limit > MAX_LIMIT {
+                return Err(Error::InvalidParameter(format!(
+                    "limit must be between 1 and {MAX_LIMIT}"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn create_url(&self) -> String {
+        let mut created_link = "query?function=NEWS_SENTIMENT".to_string();
+
+        if !self.tickers.is_empty() {
+            let _ = write!(created_link, "&tickers={}", self.tickers.join(","));
+        }
+
+        if !self.topics.is_empty() {
+            let topics = self
+                .topics
+                .iter()
+                .map(|topic| topic.as_str())
+                .collect::<Vec<_>>()
+                .join(",");
+            let _ = write!(created_link, "&topics={topics}");
+        }
+
+        if let Some(time_from) = self.time_from {
+            let _ = write!(created_link, "&time_from={time_from}");
+        }
+
+        if let Some(time_to) = self.time_to {
+            let _ = write!(created_link, "&time_to={time_to}");
+        }
+
+        if let Some(sort) = self.sort {
+            match sort {
+                NewsSort::Latest => created_link.push_str("&sort=LATEST"),
+                NewsSort::Earliest => created_link.push_str("&sort=EARLIEST"),
+                NewsSort::Relevance => created_link.push_str("&sort=RELEVANCE"),
+            }
+        }
+
+        if let Some(limit) = self.limit {
+            let _ = write!(created_link, "&limit={limit}");
+        }
+
+        created_link
+    }
+}
@@ -0,0 +1,749 @@
+//! Module for returning company information and financial ratios
+//!
+//! This API returns the company information, financial ratios, and other key
+//! metrics for the equity specified. Data is generally refreshed on the same
+//! day a company reports its latest earnings and financials.
+//!
+//! You can read about [Company Overview][overview] API and what it returns
+//! on alphavantage documentation
+//!
+//! [overview]: https://www.alphavantage.co/documentation/#company-overview
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::ApiClient;
+use crate::deserialize::from_none_str;
+use crate::error::{decode_error, detect_common_helper_error, Error, Result};
+
+/// Struct to store company overview of symbol
+///
+/// Serialized as object with snake case name of each value such as `symbol`,
+/// `market_capitalization`, `pe_ratio` and `week_52_high`. Value which is not
+/// available is serialized as null
+///
+/// Symbol, asset type, name, exchange and currency are required. Other value
+/// missing from response is stored as empty string or None
+///
+/// ```
+/// use alpha_vantage::error::Error;
+/// use alpha_vantage::mock::MockClient;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = MockClient::new().response(
+///         "function=OVERVIEW",
+///         r#"{"Symbol": "IBM", "AssetType": "Common Stock", "Name": "IBM",
+///             "Exchange": "NYSE", "Currency": "USD", "PERatio": "22.5"}"#,
+///     );
+///     let api = alpha_vantage::set_api("demo", client);
+///     let overview = api.company_overview("IBM").json().await.unwrap();
+///     assert_eq!(overview.pe_ratio(), Some(22.5));
+///     assert_eq!(overview.sector(), "");
+///     assert_eq!(overview.beta(), None);
+///
+///     let client = MockClient::new().response(
+///         "function=OVERVIEW",
+///         r#"{"AssetType": "Common Stock", "Name": "IBM", "Exchange": "NYSE",
+///             "Currency": "USD"}"#,
+///     );
+///     let api = alpha_vantage::set_api("demo", client);
+///     let error = api.company_overview("IBM").json().await.err().unwrap();
+///     assert!(matches!(error, Error::DecodeJsonToStruct { .. }));
+///     assert!(error.to_string().contains("Symbol"));
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct CompanyOverview {
+    #[serde(rename(deserialize = "Symbol"), alias = "symbol")]
+    symbol: String,
+    #[serde(rename(deserialize = "AssetType"), alias = "asset_type")]
+    asset_type: String,
+    #[serde(rename(deserialize = "Name"), alias = "name")]
+    name: String,
+    #[serde(rename(deserialize = "Description"), alias = "description", default)]
+    description: String,
+    #[serde(rename(deserialize = "CIK"), alias = "cik", default)]
+    cik: String,
+    #[serde(rename(deserialize = "Exchange"), alias = "exchange")]
+    exchange: String,
+    #[serde(rename(deserialize = "Currency"), alias = "currency")]
+    currency: String,
+    #[serde(rename(deserialize = "Country"), alias = "country", default)]
+    country: String,
+    #[serde(rename(deserialize = "Sector"), alias = "sector", default)]
+    sector: String,
+    #[serde(rename(deserialize = "Industry"), alias = "industry", default)]
+    industry: String,
+    #[serde(rename(deserialize = "Address"), alias = "address", default)]
+    address: String,
+    #[serde(
+        rename(deserialize = "FiscalYearEnd"),
+        alias = "fiscal_year_end",
+        default
+    )]
+    fiscal_year_end: String,
+    #[serde(
+        rename(deserialize = "LatestQuarter"),
+        alias = "latest_quarter",
+        default
+    )]
+    latest_quarter: String,
+    #[serde(
+        rename(deserialize = "MarketCapitalization"),
+        alias = "market_capitalization",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    market_capitalization: Option<u64>,
+    #[serde(
+        rename(deserialize = "EBITDA"),
+        alias = "ebitda",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    ebitda: Option<i64>,
+    #[serde(
+        rename(deserialize = "PERatio"),
+        alias = "pe_ratio",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    pe_ratio: Option<f64>,
+    #[serde(
+        rename(deserialize = "PEGRatio"),
+        alias = "peg_ratio",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    peg_ratio: Option<f64>,
+    #[serde(
+        rename(deserialize = "BookValue"),
+        alias = "book_value",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    book_value: Option<f64>,
+    #[serde(
+        rename(deserialize = "DividendPerShare"),
+        alias = "dividend_per_share",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    dividend_per_share: Option<f64>,
+    #[serde(
+        rename(deserialize = "DividendYield"),
+        alias = "dividend_yield",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    dividend_yield: Option<f64>,
+    #[serde(
+        rename(deserialize = "EPS"),
+        alias = "eps",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    eps: Option<f64>,
+    #[serde(
+        rename(deserialize = "RevenuePerShareTTM"),
+        alias = "revenue_per_share_ttm",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    revenue_per_share_ttm: Option<f64>,
+    #[serde(
+        rename(deserialize = "ProfitMargin"),
+        alias = "profit_margin",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    profit_margin: Option<f64>,
+    #[serde(
+        rename(deserialize = "OperatingMarginTTM"),
+        alias = "operating_margin_ttm",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    operating_margin_ttm: Option<f64>,
+    #[serde(
+        rename(deserialize = "ReturnOnAssetsTTM"),
+        alias = "return_on_assets_ttm",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    return_on_assets_ttm: Option<f64>,
+    #[serde(
+        rename(deserialize = "ReturnOnEquityTTM"),
+        alias = "return_on_equity_ttm",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    return_on_equity_ttm: Option<f64>,
+    #[serde(
+        rename(deserialize = "RevenueTTM"),
+        alias = "revenue_ttm",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    revenue_ttm: Option<i64>,
+    #[serde(
+        rename(deserialize = "GrossProfitTTM"),
+        alias = "gross_profit_ttm",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    gross_profit_ttm: Option<i64>,
+    #[serde(
+        rename(deserialize = "DilutedEPSTTM"),
+        alias = "diluted_eps_ttm",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    diluted_eps_ttm: Option<f64>,
+    #[serde(
+        rename(deserialize = "QuarterlyEarningsGrowthYOY"),
+        alias = "quarterly_earnings_growth_yoy",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    quarterly_earnings_growth_yoy: Option<f64>,
+    #[serde(
+        rename(deserialize = "QuarterlyRevenueGrowthYOY"),
+        alias = "quarterly_revenue_growth_yoy",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    quarterly_revenue_growth_yoy: Option<f64>,
+    #[serde(
+        rename(deserialize = "AnalystTargetPrice"),
+        alias = "analyst_target_price",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    analyst_target_price: Option<f64>,
+    #[serde(
+        rename(deserialize = "AnalystRatingStrongBuy"),
+        alias = "analyst_rating_strong_buy",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    analyst_rating_strong_buy: Option<u32>,
+    #[serde(
+        rename(deserialize = "AnalystRatingBuy"),
+        alias = "analyst_rating_buy",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    analyst_rating_buy: Option<u32>,
+    #[serde(
+        rename(deserialize = "AnalystRatingHold"),
+        alias = "analyst_rating_hold",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    analyst_rating_hold: Option<u32>,
+    #[serde(
+        rename(deserialize = "AnalystRatingSell"),
+        alias = "analyst_rating_sell",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    analyst_rating_sell: Option<u32>,
+    #[serde(
+        rename(deserialize = "AnalystRatingStrongSell"),
+        alias = "analyst_rating_strong_sell",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    analyst_rating_strong_sell: Option<u32>,
+    #[serde(
+        rename(deserialize = "TrailingPE"),
+        alias = "trailing_pe",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    trailing_pe: Option<f64>,
+    #[serde(
+        rename(deserialize = "ForwardPE"),
+        alias = "forward_pe",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    forward_pe: Option<f64>,
+    #[serde(
+        rename(deserialize = "PriceToSalesRatioTTM"),
+        alias = "price_to_sales_ratio_ttm",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    price_to_sales_ratio_ttm: Option<f64>,
+    #[serde(
+        rename(deserialize = "PriceToBookRatio"),
+        alias = "price_to_book_ratio",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    price_to_book_ratio: Option<f64>,
+    #[serde(
+        rename(deserialize = "EVToRevenue"),
+        alias = "ev_to_revenue",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    ev_to_revenue: Option<f64>,
+    #[serde(
+        rename(deserialize = "EVToEBITDA"),
+        alias = "ev_to_ebitda",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    ev_to_ebitda: Option<f64>,
+    #[serde(
+        rename(deserialize = "Beta"),
+        alias = "beta",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    beta: Option<f64>,
+    #[serde(
+        rename(deserialize = "52WeekHigh"),
+        alias = "week_52_high",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    week_52_high: Option<f64>,
+    #[serde(
+        rename(deserialize = "52WeekLow"),
+        alias = "week_52_low",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    week_52_low: Option<f64>,
+    #[serde(
+        rename(deserialize = "50DayMovingAverage"),
+        alias = "day_50_moving_average",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    day_50_moving_average: Option<f64>,
+    #[serde(
+        rename(deserialize = "200DayMovingAverage"),
+        alias = "day_200_moving_average",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    day_200_moving_average: Option<f64>,
+    #[serde(
+        rename(deserialize = "SharesOutstanding"),
+        alias = "shares_outstanding",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    shares_outstanding: Option<u64>,
+    #[serde(
+        rename(deserialize = "DividendDate"),
+        alias = "dividend_date",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    dividend_date: Option<String>,
+    #[serde(
+        rename(deserialize = "ExDividendDate"),
+        alias = "ex_dividend_date",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    ex_dividend_date: Option<String>,
+}
+
+impl CompanyOverview {
+    /// Return symbol
+    ///
+    /// # Example
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let overview = api.company_overview("IBM").json().await.unwrap();
+    ///     assert_eq!(overview.symbol(), "IBM");
+    ///     assert_eq!(overview.sector(), "TECHNOLOGY");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Return asset type
+    #[must_use]
+    pub fn asset_type(&self) -> &str {
+        &self.asset_type
+    }
+
+    /// Return name of company
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return description of company
+    #[must_use]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Return central index key of company
+    #[must_use]
+    pub fn cik(&self) -> &str {
+        &self.cik
+    }
+
+    /// Return exchange where symbol is listed
+    #[must_use]
+    pub fn exchange(&self) -> &str {
+        &self.exchange
+    }
+
+    /// Return currency
+    #[must_use]
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Return country
+    #[must_use]
+    pub fn country(&self) -> &str {
+        &self.country
+    }
+
+    /// Return sector
+    #[must_use]
+    pub fn sector(&self) -> &str {
+        &self.sector
+    }
+
+    /// Return industry
+    #[must_use]
+    pub fn industry(&self) -> &str {
+        &self.industry
+    }
+
+    /// Return address of company
+    #[must_use]
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Return month of fiscal year end
+    #[must_use]
+    pub fn fiscal_year_end(&self) -> &str {
+        &self.fiscal_year_end
+    }
+
+    /// Return latest quarter
+    #[must_use]
+    pub fn latest_quarter(&self) -> &str {
+        &self.latest_quarter
+    }
+
+    /// Return market capitalization. Return None if api return none
+    #[must_use]
+    pub fn market_capitalization(&self) -> Option<u64> {
+        self.market_capitalization
+    }
+
+    /// Return EBITDA. Return None if api return none
+    #[must_use]
+    pub fn ebitda(&self) -> Option<i64> {
+        self.ebitda
+    }
+
+    /// Return PE ratio. Return None if api return none
+    #[must_use]
+    pub fn pe_ratio(&self) -> Option<f64> {
+        self.pe_ratio
+    }
+
+    /// Return PEG ratio. Return None if api return none
+    #[must_use]
+    pub fn peg_ratio(&self) -> Option<f64> {
+        self.peg_ratio
+    }
+
+    /// Return book value. Return None if api return none
+    #[must_use]
+    pub fn book_value(&self) -> Option<f64> {
+        self.book_value
+    }
+
+    /// Return dividend per share. Return None if api return none
+    #[must_use]
+    pub fn dividend_per_share(&self) -> Option<f64> {
+        self.dividend_per_share
+    }
+
+    /// Return dividend yield. Return None if api return none
+    #[must_use]
+    pub fn dividend_yield(&self) -> Option<f64> {
+        self.dividend_yield
+    }
+
+    /// Return earning per share. Return None if api return none
+    #[must_use]
+    pub fn eps(&self) -> Option<f64> {
+        self.eps
+    }
+
+    /// Return trailing twelve month revenue per share. Return None if api
+    /// return none
+    #[must_use]
+    pub fn revenue_per_share_ttm(&self) -> Option<f64> {
+        self.revenue_per_share_ttm
+    }
+
+    /// Return profit margin. Return None if api return none
+    #[must_use]
+    pub fn profit_margin(&self) -> Option<f64> {
+        self.profit_margin
+    }
+
+    /// Return trailing twelve month operating margin. Return None if api return
+    /// none
+    #[must_use]
+    pub fn operating_margin_ttm(&self) -> Option<f64> {
+        self.operating_margin_ttm
+    }
+
+    /// Return trailing twelve month return on assets. Return None if api return
+    /// none
+    #[must_use]
+    pub fn return_on_assets_ttm(&self) -> Option<f64> {
+        self.return_on_assets_ttm
+    }
+
+    /// Return trailing twelve month return on equity. Return None if api return
+    /// none
+    #[must_use]
+    pub fn return_on_equity_ttm(&self) -> Option<f64> {
+        self.return_on_equity_ttm
+    }
+
+    /// Return trailing twelve month revenue. Return None if api return none
+    #[must_use]
+    pub fn revenue_ttm(&self) -> Option<i64> {
+        self.revenue_ttm
+    }
+
+    /// Return trailing twelve month gross profit. Return None if api return
+    /// none
+    #[must_use]
+    pub fn gross_profit_ttm(&self) -> Option<i64> {
+        self.gross_profit_ttm
+    }
+
+    /// Return trailing twelve month diluted earning per share. Return None if
+    /// api return none
+    #[must_use]
+    pub fn diluted_eps_ttm(&self) -> Option<f64> {
+        self.diluted_eps_ttm
+    }
+
+    /// Return year over year quarterly earnings growth. Return None if api
+    /// return none
+    #[must_use]
+    pub fn quarterly_earnings_growth_yoy(&self) -> Option<f64> {
+        self.quarterly_earnings_growth_yoy
+    }
+
+    /// Return year over year quarterly revenue growth. Return None if api
+    /// return none
+    #[must_use]
+    pub fn quarterly_revenue_growth_yoy(&self) -> Option<f64> {
+        self.quarterly_revenue_growth_yoy
+    }
+
+    /// Return analyst target price. Return None if api return none
+    #[must_use]
+    pub fn analyst_target_price(&self) -> Option<f64> {
+        self.analyst_target_price
+    }
+
+    /// Return number of analyst strong buy rating. Return None if api return
+    /// none
+    #[must_use]
+    pub fn analyst_rating_strong_buy(&self) -> Option<u32> {
+        self.analyst_rating_strong_buy
+    }
+
+    /// Return number of analyst buy rating. Return None if api return none
+    #[must_use]
+    pub fn analyst_rating_buy(&self) -> Option<u32> {
+        self.analyst_rating_buy
+    }
+
+    /// Return number of analyst hold rating. Return None if api return none
+    #[must_use]
+    pub fn analyst_rating_hold(&self) -> Option<u32> {
+        self.analyst_rating_hold
+    }
+
+    /// Return number of analyst sell rating. Return None if api return none
+    #[must_use]
+    pub fn analyst_rating_sell(&self) -> Option<u32> {
+        self.analyst_rating_sell
+    }
+
+    /// Return number of analyst strong sell rating. Return None if api return
+    /// none
+    #[must_use]
+    pub fn analyst_rating_strong_sell(&self) -> Option<u32> {
+        self.analyst_rating_strong_sell
+    }
+
+    /// Return trailing PE. Return None if api return none
+    #[must_use]
+    pub fn trailing_pe(&self) -> Option<f64> {
+        self.trailing_pe
+    }
+
+    /// Return forward PE. Return None if api return none
+    ///
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let overview = api.company_overview("IBM").json().await.unwrap();
+    ///     assert_eq!(overview.trailing_pe(), Some(22.23));
+    ///     assert_eq!(overview.forward_pe(), None);
+    ///     assert_eq!(overview.ex_dividend_date(), None);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn forward_pe(&self) -> Option<f64> {
+        self.forward_pe
+    }
+
+    /// Return trailing twelve month price to sales ratio. Return None if api
+    /// return none
+    #[must_use]
+    pub fn price_to_sales_ratio_ttm(&self) -> Option<f64> {
+        self.price_to_sales_ratio_ttm
+    }
+
+    /// Return price to book ratio. Return None if api return none
+    #[must_use]
+    pub fn price_to_book_ratio(&self) -> Option<f64> {
+        self.price_to_book_ratio
+    }
+
+    /// Return enterprise value to revenue. Return None if api return none
+    #[must_use]
+    pub fn ev_to_revenue(&self) -> Option<f64> {
+        self.ev_to_revenue
+    }
+
+    /// Return enterprise value to EBITDA. Return None if api return none
+    #[must_use]
+    pub fn ev_to_ebitda(&self) -> Option<f64> {
+        self.ev_to_ebitda
+    }
+
+    /// Return beta. Return None if api return none
+    #[must_use]
+    pub fn beta(&self) -> Option<f64> {
+        self.beta
+    }
+
+    /// Return 52 week high. Return None if api return none
+    #[must_use]
+    pub fn week_52_high(&self) -> Option<f64> {
+        self.week_52_high
+    }
+
+    /// Return 52 week low. Return None if api return none
+    #[must_use]
+    pub fn week_52_low(&self) -> Option<f64> {
+        self.week_52_low
+    }
+
+    /// Return 50 day moving average. Return None if api return none
+    #[must_use]
+    pub fn day_50_moving_average(&self) -> Option<f64> {
+        self.day_50_moving_average
+    }
+
+    /// Return 200 day moving average. Return None if api return none
+    #[must_use]
+    pub fn day_200_moving_average(&self) -> Option<f64> {
+        self.day_200_moving_average
+    }
+
+    /// Return number of shares outstanding. Return None if api return none
+    #[must_use]
+    pub fn shares_outstanding(&self) -> Option<u64> {
+        self.shares_outstanding
+    }
+
+    /// Return dividend date. Return None if api return none
+    #[must_use]
+    pub fn dividend_date(&self) -> Option<&str> {
+        self.dividend_date.as_deref()
+    }
+
+    /// Return ex dividend date. Return None if api return none
+    #[must_use]
+    pub fn ex_dividend_date(&self) -> Option<&str> {
+        self.ex_dividend_date.as_deref()
+    }
+}
+
+/// Struct used for creating company overview
+#[derive(Debug, Deserialize)]
+pub(crate) struct CompanyOverviewHelper {
+    #[serde(rename = "Information")]
+    information: Option<String>,
+    #[serde(rename = "Error Message")]
+    error_message: Option<String>,
+    #[serde(rename = "Note")]
+    note: Option<String>,
+    #[serde(flatten)]
+    overview: Map<String, Value>,
+}
+
+impl CompanyOverviewHelper {
+    /// Function which convert `CompanyOverviewHelper` to `CompanyOverview`.
+    /// API returns empty object for unknown symbol. Overview is decoded only
+    /// after checking error so error response is not reported as missing
+    /// field
+    fn convert(self) -> Result<CompanyOverview> {
+        detect_common_helper_error(self.information, self.error_message, self.note)?;
+        if self.overview.is_empty() {
+            return Err(Error::EmptyResponse);
+        }
+        serde_path_to_error::deserialize(Value::Object(self.overview))
+            .map_err(|error| decode_error(&error, None))
+    }
+}
+
+/// Builder to help create `CompanyOverview`
+pub struct CompanyOverviewBuilder<'a> {
+    api_client: &'a ApiClient,
+    symbol: &'a str,
+}
+
+impl<'a> CompanyOverviewBuilder<'a> {
+    crate::json_data_struct!(CompanyOverview, CompanyOverviewHelper);
+
+    /// Create new `CompanyOverviewBuilder` with help of `APIClient`
+    #[must_use]
+    pub fn new(api_client: &'a ApiClient, symbol: &'a str) -> Self {
+        Self { api_client, symbol }
+    }
+
+    fn create_url(&self) -> String {
+        format!("query?function=OVERVIEW&symbol={}", self.symbol)
+    }
+}
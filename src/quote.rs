@@ -8,39 +8,99 @@
 //!
 //! [quote]: https://www.alphavantage.co/documentation/#latestprice
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::api::ApiClient;
-use crate::deserialize::{from_str, percent_f64};
+#[cfg(feature = "chrono")]
+use crate::deserialize::parse_date;
+use crate::deserialize::{from_str, percent_f64, Price};
 use crate::error::{detect_common_helper_error, Error, Result};
 
 /// Struct storing Global Quote Value
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 struct GlobalQuote {
-    #[serde(rename = "01. symbol")]
+    #[serde(rename(deserialize = "01. symbol"), alias = "symbol")]
     symbol: String,
-    #[serde(rename = "02. open", deserialize_with = "from_str")]
-    open: f64,
-    #[serde(rename = "03. high", deserialize_with = "from_str")]
-    high: f64,
-    #[serde(rename = "04. low", deserialize_with = "from_str")]
-    low: f64,
-    #[serde(rename = "05. price", deserialize_with = "from_str")]
-    price: f64,
-    #[serde(rename = "06. volume", deserialize_with = "from_str")]
+    #[serde(
+        rename(deserialize = "02. open"),
+        alias = "open",
+        deserialize_with = "from_str"
+    )]
+    open: Price,
+    #[serde(
+        rename(deserialize = "03. high"),
+        alias = "high",
+        deserialize_with = "from_str"
+    )]
+    high: Price,
+    #[serde(
+        rename(deserialize = "04. low"),
+        alias = "low",
+        deserialize_with = "from_str"
+    )]
+    low: Price,
+    #[serde(
+        rename(deserialize = "05. price"),
+        alias = "price",
+        deserialize_with = "from_str"
+    )]
+    price: Price,
+    #[serde(
+        rename(deserialize = "06. volume"),
+        alias = "volume",
+        deserialize_with = "from_str"
+    )]
     volume: u64,
-    #[serde(rename = "07. latest trading day")]
+    #[serde(
+        rename(
+            serialize = "latest_trading_day",
+            deserialize = "07. latest trading day"
+        ),
+        alias = "latest_trading_day"
+    )]
     last_day: String,
-    #[serde(rename = "08. previous close", deserialize_with = "from_str")]
-    previous_close: f64,
-    #[serde(rename = "09. change", deserialize_with = "from_str")]
-    change: f64,
-    #[serde(rename = "10. change percent", deserialize_with = "percent_f64")]
+    #[serde(
+        rename(deserialize = "08. previous close"),
+        alias = "previous_close",
+        deserialize_with = "from_str"
+    )]
+    previous_close: Price,
+    #[serde(
+        rename(deserialize = "09. change"),
+        alias = "change",
+        deserialize_with = "from_str"
+    )]
+    change: Price,
+    #[serde(
+        rename(deserialize = "10. change percent"),
+        alias = "change_percent",
+        deserialize_with = "percent_f64"
+    )]
     change_percent: f64,
 }
 
 /// Struct for storing Quote related information
-#[derive(Default)]
+///
+/// Serialized as object with `symbol`, `open`, `high`, `low`, `price`,
+/// `volume`, `latest_trading_day`, `previous_close`, `change` and
+/// `change_percent`. Price values are serialized as number or as string of
+/// exact decimal when `decimal` feature is enabled
+///
+/// ```
+/// #[tokio::main]
+/// async fn main() {
+///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+///     let api = alpha_vantage::set_api("demo", client);
+///     let quote = api.quote("MSFT").json().await.unwrap();
+///     let json = serde_json::to_string(&quote).unwrap();
+///     assert!(json.contains(r#""price":315.75"#) || json.contains(r#""price":"315.7500""#));
+///     let restored: alpha_vantage::quote::Quote = serde_json::from_str(&json).unwrap();
+///     assert_eq!(restored.price(), quote.price());
+///     assert_eq!(restored.last_trading(), quote.last_trading());
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct Quote {
     global_quote: GlobalQuote,
 }
@@ -49,25 +109,67 @@ impl Quote {
     /// return open value
     #[must_use]
     pub fn open(&self) -> f64 {
-        self.global_quote.open
+        self.global_quote.open.value
+    }
+
+    /// return open value as exact decimal. return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn open_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.global_quote.open.decimal
     }
 
     /// return high value
     #[must_use]
     pub fn high(&self) -> f64 {
-        self.global_quote.high
+        self.global_quote.high.value
+    }
+
+    /// return high value as exact decimal. return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn high_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.global_quote.high.decimal
     }
 
     /// return low value
     #[must_use]
     pub fn low(&self) -> f64 {
-        self.global_quote.low
+        self.global_quote.low.value
+    }
+
+    /// return low value as exact decimal. return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn low_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.global_quote.low.decimal
     }
 
     /// return price value
     #[must_use]
     pub fn price(&self) -> f64 {
-        self.global_quote.price
+        self.global_quote.price.value
+    }
+
+    /// return price value as exact decimal. return None if value
+    /// cannot be represented as decimal
+    ///
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let quote = api.quote("MSFT").json().await.unwrap();
+    ///     assert_eq!(quote.price_decimal().unwrap().to_string(), "315.7500");
+    /// }
+    /// ```
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn price_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.global_quote.price.decimal
     }
 
     /// return volume
@@ -79,13 +181,29 @@ impl Quote {
     /// return previous
     #[must_use]
     pub fn previous(&self) -> f64 {
-        self.global_quote.previous_close
+        self.global_quote.previous_close.value
+    }
+
+    /// return previous as exact decimal. return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn previous_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.global_quote.previous_close.decimal
     }
 
     /// return change
     #[must_use]
     pub fn change(&self) -> f64 {
-        self.global_quote.change
+        self.global_quote.change.value
+    }
+
+    /// return change as exact decimal. return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn change_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.global_quote.change.decimal
     }
 
     /// return change percent
@@ -100,12 +218,33 @@ impl Quote {
         &self.global_quote.last_day
     }
 
+    /// get last trading day as date
+    ///
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let quote = api.quote("MSFT").json().await.unwrap();
+    ///     let date = quote.last_trading_date().unwrap();
+    ///     assert_eq!(date.to_string(), quote.last_trading());
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if last trading day is not a valid date
+    #[cfg(feature = "chrono")]
+    pub fn last_trading_date(&self) -> Result<chrono::NaiveDate> {
+        parse_date(&self.global_quote.last_day)
+    }
+
     /// get symbol
     ///
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let quote = api.quote("MSFT").json().await.unwrap();
     ///     let symbol = quote.symbol();
     ///     assert_eq!(symbol, "MSFT");
@@ -0,0 +1,137 @@
+//! Module for client side rate limiting
+//!
+//! Alpha Vantage limits number of API call which can be performed per minute
+//! and per day for an API key. `RateLimiter` is a token bucket based limiter
+//! which can be attached to `ApiClient` so every request waits till quota is
+//! available instead of returning rate limit note from API.
+
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+const SECONDS_PER_MINUTE: f64 = 60.0;
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// Token bucket which refill continuously at constant rate
+#[derive(Debug)]
+struct Bucket {
+    capacity: f64,
+    tokens: f64,
+    refill_per_second: f64,
+    last_refill: Instant,
+}
+
+impl Bucket {
+    fn new(calls: u32, period_seconds: f64) -> Self {
+        let capacity = f64::from(calls.max(1));
+        Self {
+            capacity,
+            tokens: capacity,
+            refill_per_second: capacity / period_seconds,
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Reserve a token from bucket and return duration after which reserved
+    /// token is available. Tokens can go negative so that concurrent callers
+    /// are queued one after another
+    fn reserve(&mut self, now: Instant) -> Duration {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.tokens =
+            (self.tokens + elapsed.as_secs_f64() * self.refill_per_second).min(self.capacity);
+        self.last_refill = now;
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.refill_per_second)
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Buckets {
+    minute: Bucket,
+    day: Option<Bucket>,
+}
+
+/// Client side token bucket rate limiter
+///
+/// Cloned limiter shares same quota with original limiter so single limiter
+/// can be used by multiple `ApiClient` which uses same API key
+///
+/// ```
+/// use alpha_vantage::rate_limit::RateLimiter;
+/// let limiter = RateLimiter::new(5).daily_limit(500);
+/// let api = alpha_vantage::set_api("some_key", reqwest::Client::new()).rate_limiter(limiter);
+/// ```
+///
+/// Once quota is used up next call is delayed till token is refilled
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use alpha_vantage::mock::MockClient;
+/// use alpha_vantage::rate_limit::RateLimiter;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = MockClient::from_directory("tests/fixtures").unwrap();
+///     let api = alpha_vantage::set_api("demo", client).rate_limiter(RateLimiter::new(1));
+///     api.quote("MSFT").json().await.unwrap();
+///
+///     // second call needs to wait a minute for next token
+///     let second = tokio::spawn(async move { api.quote("MSFT").json().await });
+///     futures_timer::Delay::new(Duration::from_millis(200)).await;
+///     assert!(!second.is_finished());
+///     second.abort();
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    buckets: Arc<Mutex<Buckets>>,
+}
+
+impl RateLimiter {
+    /// Create new rate limiter which allows given number of calls per minute
+    #[must_use]
+    pub fn new(calls_per_minute: u32) -> Self {
+        Self {
+            buckets: Arc::new(Mutex::new(Buckets {
+                minute: Bucket::new(calls_per_minute, SECONDS_PER_MINUTE),
+                day: None,
+            })),
+        }
+    }
+
+    /// Set maximum number of calls which can be performed per day
+    #[must_use]
+    pub fn daily_limit(self, calls_per_day: u32) -> Self {
+        self.lock().day = Some(Bucket::new(calls_per_day, SECONDS_PER_DAY));
+        self
+    }
+
+    /// Reserve a call and return duration which needs to be waited before
+    /// performing call
+    fn reserve(&self) -> Duration {
+        let now = Instant::now();
+        let mut buckets = self.lock();
+        let minute_wait = buckets.minute.reserve(now);
+        let day_wait = buckets
+            .day
+            .as_mut()
+            .map_or(Duration::ZERO, |day| day.reserve(now));
+        minute_wait.max(day_wait)
+    }
+
+    /// Wait till a call can be performed without exceeding limit
+    pub(crate) async fn wait(&self) {
+        let delay = self.reserve();
+        if !delay.is_zero() {
+            futures_timer::Delay::new(delay).await;
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Buckets> {
+        self.buckets.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
@@ -0,0 +1,238 @@
+//! Module for recording and replaying API response
+//!
+//! `RecordingClient` wraps a real `HttpClient` and stores every response
+//! returned by API in a cassette file. Same cassette can later be replayed
+//! without network access so real payloads can be used for regression test.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::client::HttpClient;
+use crate::error::{Error, Result};
+use crate::mock::query_params;
+
+/// Single request and response pair stored in cassette
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    request: String,
+    response: String,
+}
+
+/// Return query of request without API key. Query parameter are sorted so
+/// order of parameter does not affect matching
+fn redacted_query(path: &str) -> String {
+    let mut params = query_params(path);
+    params.sort();
+    params
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Client which records response of wrapped client to a cassette or replays
+/// response from a cassette
+///
+/// Cassette is a JSON file containing list of request query and response.
+/// API key is never written to cassette. Both record and replay mode return
+/// same type so test can switch between them without any other change.
+///
+/// ```no_run
+/// use alpha_vantage::recording::RecordingClient;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let record = std::env::var("RECORD").is_ok();
+///     let client = if record {
+///         RecordingClient::record(reqwest::Client::new(), "tests/cassettes/quote.json")
+///     } else {
+///         RecordingClient::replay_generic("tests/cassettes/quote.json").unwrap()
+///     };
+///     let api = alpha_vantage::set_api("some_key", client);
+///     let quote = api.quote("MSFT").json().await.unwrap();
+///     assert_eq!(quote.symbol(), "MSFT");
+/// }
+/// ```
+pub struct RecordingClient<C> {
+    client: Option<C>,
+    cassette: PathBuf,
+    interactions: Mutex<Vec<Interaction>>,
+}
+
+impl<C> RecordingClient<C> {
+    /// Create new client which performs request using client and records
+    /// response to cassette. Existing cassette is overwritten
+    ///
+    /// ```
+    /// use alpha_vantage::mock::MockClient;
+    /// use alpha_vantage::recording::RecordingClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let cassette = std::env::temp_dir().join("alpha_vantage_quote_cassette.json");
+    ///     let client = MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("secret_key", RecordingClient::record(client, &cassette));
+    ///     api.quote("MSFT").json().await.unwrap();
+    ///     let recorded = std::fs::read_to_string(&cassette).unwrap();
+    ///     assert!(recorded.contains("GLOBAL_QUOTE"));
+    ///     assert!(!recorded.contains("secret_key"));
+    ///
+    ///     let client = RecordingClient::replay(&cassette).unwrap();
+    ///     let api = alpha_vantage::set_api("other_key", client);
+    ///     let quote = api.quote("MSFT").json().await.unwrap();
+    ///     assert_eq!(quote.symbol(), "MSFT");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn record<P>(client: C, cassette: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self {
+            client: Some(client),
+            cassette: cassette.into(),
+            interactions: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Create new client which replays response stored in cassette and has
+    /// same type as client returned by `record`. Useful when same variable
+    /// holds client of both mode, otherwise use `RecordingClient::replay`
+    ///
+    /// # Errors
+    /// Raise error if cassette cannot be read or is not a valid cassette
+    pub fn replay_generic<P>(cassette: P) -> std::io::Result<Self>
+    where
+        P: Into<PathBuf>,
+    {
+        let cassette = cassette.into();
+        let interactions = serde_json::from_str(&fs::read_to_string(&cassette)?)?;
+        Ok(Self {
+            client: None,
+            cassette,
+            interactions: Mutex::new(interactions),
+        })
+    }
+
+    /// Return path of cassette
+    #[must_use]
+    pub fn cassette(&self) -> &Path {
+        &self.cassette
+    }
+
+    /// Return true if client is recording response
+    #[must_use]
+    pub fn is_recording(&self) -> bool {
+        self.client.is_some()
+    }
+
+    fn interactions(&self) -> MutexGuard<'_, Vec<Interaction>> {
+        self.interactions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Return recorded response for request
+    fn replay_output(&self, path: &str) -> Result<String> {
+        let request = redacted_query(path);
+        self.interactions()
+            .iter()
+            .find(|interaction| interaction.request == request)
+            .map(|interaction| interaction.response.clone())
+            .ok_or(Error::MockResponseMissing(request))
+    }
+
+    /// Store response for request and write all interaction to cassette
+    fn record_output(&self, path: &str, response: &str) -> Result<()> {
+        let request = redacted_query(path);
+        let mut interactions = self.interactions();
+        interactions.retain(|interaction| interaction.request != request);
+        interactions.push(Interaction {
+            request,
+            response: response.to_string(),
+        });
+        let write_cassette = || -> std::io::Result<()> {
+            if let Some(parent) = self.cassette.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(
+                &self.cassette,
+                serde_json::to_string_pretty(&*interactions)?,
+            )
+        };
+        write_cassette().map_err(|source| {
+            Error::CassetteWrite {
+                path: self.cassette.clone(),
+                source,
+            }
+        })
+    }
+}
+
+impl RecordingClient<()> {
+    /// Create new client which replays response stored in cassette
+    ///
+    /// ```
+    /// use alpha_vantage::error::Error;
+    /// use alpha_vantage::recording::RecordingClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let cassette = std::env::temp_dir().join("alpha_vantage_empty_cassette.json");
+    ///     std::fs::write(&cassette, "[]").unwrap();
+    ///     let client = RecordingClient::replay(&cassette).unwrap();
+    ///     assert!(!client.is_recording());
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let error = api.quote("MSFT").json().await.err().unwrap();
+    ///     assert!(matches!(error, Error::MockResponseMissing(_)));
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if cassette cannot be read or is not a valid cassette
+    pub fn replay<P>(cassette: P) -> std::io::Result<Self>
+    where
+        P: Into<PathBuf>,
+    {
+        Self::replay_generic(cassette)
+    }
+}
+
+#[async_trait]
+impl HttpClient for RecordingClient<()> {
+    async fn get_alpha_vantage_provider_output(&self, path: &str) -> Result<String> {
+        self.replay_output(path)
+    }
+
+    async fn get_rapid_api_provider_output(&self, path: &str, _api_key: &str) -> Result<String> {
+        self.replay_output(path)
+    }
+}
+
+#[async_trait]
+impl<C> HttpClient for RecordingClient<C>
+where
+    C: HttpClient + Send + Sync,
+{
+    async fn get_alpha_vantage_provider_output(&self, path: &str) -> Result<String> {
+        let Some(client) = &self.client else {
+            return self.replay_output(path);
+        };
+        let output = client.get_alpha_vantage_provider_output(path).await?;
+        self.record_output(path, &output)?;
+        Ok(output)
+    }
+
+    async fn get_rapid_api_provider_output(&self, path: &str, api_key: &str) -> Result<String> {
+        let Some(client) = &self.client else {
+            return self.replay_output(path);
+        };
+        let output = client.get_rapid_api_provider_output(path, api_key).await?;
+        self.record_output(path, &output)?;
+        Ok(output)
+    }
+}
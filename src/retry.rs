@@ -0,0 +1,149 @@
+//! Module for retrying failed API call
+//!
+//! `RetryPolicy` can be attached to `ApiClient` to retry call which failed
+//! because of transport failure or because API returned rate limit note. Hard
+//! failures such as error message returned by API are never retried.
+
+use std::time::Duration;
+
+use crate::error::{is_retryable_rate_limit_response, Result};
+
+const DEFAULT_MAX_DELAY_SECONDS: u64 = 60;
+
+/// Policy which defines how failed API call are retried
+///
+/// Delay between attempts grows exponentially starting from base delay and is
+/// capped to max delay. When jitter is enabled a random delay between half
+/// and full value of computed delay is used
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use alpha_vantage::retry::RetryPolicy;
+/// let policy = RetryPolicy::new(5)
+///     .base_delay(Duration::from_secs(2))
+///     .max_delay(Duration::from_secs(60))
+///     .retry_rate_limit(true)
+///     .retry_transport_failure(true);
+/// let api = alpha_vantage::set_api("some_key", reqwest::Client::new()).retry_policy(policy);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    retry_transport_failure: bool,
+    retry_rate_limit: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl RetryPolicy {
+    /// Create new retry policy which performs at most `max_attempts` number of
+    /// attempt (including first call). By default both transport failure and
+    /// rate limit note are retried with base delay of 1 second, max delay of
+    /// 60 second and jitter enabled
+    #[must_use]
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(DEFAULT_MAX_DELAY_SECONDS),
+            jitter: true,
+            retry_transport_failure: true,
+            retry_rate_limit: true,
+        }
+    }
+
+    /// Set delay used before first retry
+    #[must_use]
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Set maximum delay between two attempts
+    #[must_use]
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Set whether random jitter should be applied to delay
+    #[must_use]
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Set whether call which failed to get output from server is retried.
+    /// Only timeout, connection failure and status code 429 or 5xx are retried
+    #[must_use]
+    pub fn retry_transport_failure(mut self, retry: bool) -> Self {
+        self.retry_transport_failure = retry;
+        self
+    }
+
+    /// Set whether call for which API returned rate limit message is retried.
+    /// Daily rate limit is never retried
+    #[must_use]
+    pub fn retry_rate_limit(mut self, retry: bool) -> Self {
+        self.retry_rate_limit = retry;
+        self
+    }
+
+    /// Return maximum number of attempts
+    #[must_use]
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Return delay which should be waited after given attempt failed
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use alpha_vantage::retry::RetryPolicy;
+    /// let policy = RetryPolicy::new(5).jitter(false);
+    /// assert_eq!(policy.delay(1), Duration::from_secs(1));
+    /// assert_eq!(policy.delay(3), Duration::from_secs(4));
+    /// assert_eq!(policy.delay(10), Duration::from_secs(60));
+    /// ```
+    #[must_use]
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+        if self.jitter {
+            delay.mul_f64(0.5 + fastrand::f64() / 2.0)
+        } else {
+            delay
+        }
+    }
+
+    /// Check if output of given attempt should be retried
+    pub(crate) fn should_retry(&self, output: &Result<String>, attempt: u32) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+        match output {
+            Ok(output) => self.retry_rate_limit && is_retryable_rate_limit_response(output),
+            Err(error) => self.retry_transport_failure && error.is_transient(),
+        }
+    }
+
+    /// Wait before performing next attempt
+    pub(crate) async fn wait(&self, attempt: u32) {
+        let delay = self.delay(attempt);
+        if !delay.is_zero() {
+            futures_timer::Delay::new(delay).await;
+        }
+    }
+}
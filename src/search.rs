@@ -8,33 +8,38 @@
 //!
 //! [symbol_search]: https://www.alphavantage.co/documentation/#symbolsearch
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::api::ApiClient;
 use crate::deserialize::from_str;
 use crate::error::{detect_common_helper_error, Error, Result};
+use crate::listing::ListingStatus;
 
 /// Struct which stores matches data for search keyword
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Match {
-    #[serde(rename = "1. symbol")]
+    #[serde(rename(deserialize = "1. symbol"), alias = "symbol")]
     symbol: String,
-    #[serde(rename = "2. name")]
+    #[serde(rename(deserialize = "2. name"), alias = "name")]
     name: String,
-    #[serde(rename = "3. type")]
+    #[serde(rename(deserialize = "3. type"), alias = "stock_type")]
     stock_type: String,
-    #[serde(rename = "4. region")]
+    #[serde(rename(deserialize = "4. region"), alias = "region")]
     region: String,
-    #[serde(rename = "5. marketOpen")]
+    #[serde(rename(deserialize = "5. marketOpen"), alias = "market_open")]
     market_open: String,
-    #[serde(rename = "6. marketClose")]
+    #[serde(rename(deserialize = "6. marketClose"), alias = "market_close")]
     market_close: String,
-    #[serde(rename = "7. timezone")]
+    #[serde(rename(deserialize = "7. timezone"), alias = "time_zone")]
     time_zone: String,
-    #[serde(rename = "8. currency")]
+    #[serde(rename(deserialize = "8. currency"), alias = "currency")]
     currency: String,
-    #[serde(rename = "9. matchScore", deserialize_with = "from_str")]
-    match_score: f64,
+    #[serde(
+        rename(deserialize = "9. matchScore"),
+        alias = "score",
+        deserialize_with = "from_str"
+    )]
+    score: f64,
 }
 
 impl Match {
@@ -43,7 +48,8 @@ impl Match {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let search = api.search("BA").json().await.unwrap();
     ///     let symbol = search.matches()[0].symbol();
     ///     assert_eq!(symbol, "BA");
@@ -59,7 +65,8 @@ impl Match {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let search = api.search("BA").json().await.unwrap();
     ///     let name = search.matches()[0].name();
     ///     assert_eq!(name, "Boeing Company");
@@ -75,7 +82,8 @@ impl Match {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let search = api.search("BA").json().await.unwrap();
     ///     let stock_type = search.matches()[0].stock_type();
     ///     assert_eq!(stock_type, "Equity");
@@ -90,7 +98,8 @@ impl Match {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let search = api.search("BA").json().await.unwrap();
     ///     let region = search.matches()[0].region();
     ///     assert_eq!(region, "United States");
@@ -105,7 +114,8 @@ impl Match {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let search = api.search("BA").json().await.unwrap();
     ///     let market_open = search.matches()[0].market_open();
     ///     assert_eq!(market_open, "09:30");
@@ -120,7 +130,8 @@ impl Match {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let search = api.search("BA").json().await.unwrap();
     ///     let market_close = search.matches()[0].market_close();
     ///     assert_eq!(market_close, "16:00");
@@ -141,7 +152,8 @@ impl Match {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let search = api.search("BA").json().await.unwrap();
     ///     let currency = search.matches()[0].currency();
     ///     assert_eq!(currency, "USD");
@@ -156,19 +168,41 @@ impl Match {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let search = api.search("BA").json().await.unwrap();
     ///     let match_score = search.matches()[0].match_score();
     ///     assert_eq!(match_score, 1.0);
     /// }
     #[must_use]
     pub fn match_score(&self) -> f64 {
-        self.match_score
+        self.score
+    }
+
+    /// Return true if symbol is still listed as per listing status
+    ///
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let search = api.search("BA").json().await.unwrap();
+    ///     let listing_status = api.listing_status().csv().await.unwrap();
+    ///     assert!(search.matches()[0].is_listed(&listing_status));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn is_listed(&self, listing_status: &ListingStatus) -> bool {
+        listing_status.is_listed(&self.symbol)
     }
 }
 
 /// struct for storing search method data
-#[derive(Default)]
+///
+/// Serialized as object with `matches` containing list of object with
+/// `symbol`, `name`, `stock_type`, `region`, `market_open`, `market_close`,
+/// `time_zone`, `currency` and `score`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Search {
     matches: Vec<Match>,
 }
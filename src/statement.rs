@@ -0,0 +1,1425 @@
+//! Module for returning financial statements of a company
+//!
+//! APIs under this section return the annual and quarterly income statements,
+//! balance sheets and cash flows with normalized fields mapped to GAAP and
+//! IFRS taxonomies of the SEC.
+//!
+//! You can read about [Income Statement][income_statement], [Balance
+//! Sheet][balance_sheet] and [Cash Flow][cash_flow] API and what it returns on
+//! alphavantage documentation
+//!
+//! [income_statement]: https://www.alphavantage.co/documentation/#income-statement
+//! [balance_sheet]: https://www.alphavantage.co/documentation/#balance-sheet
+//! [cash_flow]: https://www.alphavantage.co/documentation/#cash-flow
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::ApiClient;
+use crate::deserialize::from_none_str;
+use crate::error::{detect_common_helper_error, Error, Result};
+
+/// Struct to store annual or quarterly income statement report. Line item
+/// missing from response is stored as None while fiscal date ending and
+/// reported currency are required
+///
+/// ```
+/// use alpha_vantage::error::Error;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = alpha_vantage::mock::MockClient::new().response(
+///         "function=INCOME_STATEMENT",
+///         r#"{"symbol": "IBM", "annualReports": [{"fiscalDateEnding": "2022-12-31",
+///             "reportedCurrency": "USD", "totalRevenue": "60530000000"}], "quarterlyReports": []}"#,
+///     );
+///     let api = alpha_vantage::set_api("demo", client);
+///     let income_statement = api.income_statement("IBM").json().await.unwrap();
+///     let report = &income_statement.annual_reports()[0];
+///     assert_eq!(report.total_revenue(), Some(60_530_000_000));
+///     assert_eq!(report.gross_profit(), None);
+///
+///     let client = alpha_vantage::mock::MockClient::new().response(
+///         "function=INCOME_STATEMENT",
+///         r#"{"symbol": "IBM", "annualReports": [{"reportedCurrency": "USD"}],
+///             "quarterlyReports": []}"#,
+///     );
+///     let api = alpha_vantage::set_api("demo", client);
+///     let error = api.income_statement("IBM").json().await.err().unwrap();
+///     assert!(matches!(error, Error::DecodeJsonToStruct { .. }));
+///     assert!(error.to_string().contains("fiscalDateEnding"));
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct IncomeStatementReport {
+    #[serde(rename(deserialize = "fiscalDateEnding"), alias = "fiscal_date_ending")]
+    fiscal_date_ending: String,
+    #[serde(rename(deserialize = "reportedCurrency"), alias = "reported_currency")]
+    reported_currency: String,
+    #[serde(
+        rename(deserialize = "grossProfit"),
+        alias = "gross_profit",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    gross_profit: Option<i64>,
+    #[serde(
+        rename(deserialize = "totalRevenue"),
+        alias = "total_revenue",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    total_revenue: Option<i64>,
+    #[serde(
+        rename(deserialize = "costOfRevenue"),
+        alias = "cost_of_revenue",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    cost_of_revenue: Option<i64>,
+    #[serde(
+        rename(deserialize = "costofGoodsAndServicesSold"),
+        alias = "cost_of_goods_and_services_sold",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    cost_of_goods_and_services_sold: Option<i64>,
+    #[serde(
+        rename(deserialize = "operatingIncome"),
+        alias = "operating_income",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    operating_income: Option<i64>,
+    #[serde(
+        rename(deserialize = "sellingGeneralAndAdministrative"),
+        alias = "selling_general_and_administrative",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    selling_general_and_administrative: Option<i64>,
+    #[serde(
+        rename(deserialize = "researchAndDevelopment"),
+        alias = "research_and_development",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    research_and_development: Option<i64>,
+    #[serde(
+        rename(deserialize = "operatingExpenses"),
+        alias = "operating_expenses",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    operating_expenses: Option<i64>,
+    #[serde(
+        rename(deserialize = "investmentIncomeNet"),
+        alias = "investment_income_net",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    investment_income_net: Option<i64>,
+    #[serde(
+        rename(deserialize = "netInterestIncome"),
+        alias = "net_interest_income",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    net_interest_income: Option<i64>,
+    #[serde(
+        rename(deserialize = "interestIncome"),
+        alias = "interest_income",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    interest_income: Option<i64>,
+    #[serde(
+        rename(deserialize = "interestExpense"),
+        alias = "interest_expense",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    interest_expense: Option<i64>,
+    #[serde(
+        rename(deserialize = "nonInterestIncome"),
+        alias = "non_interest_income",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    non_interest_income: Option<i64>,
+    #[serde(
+        rename(deserialize = "otherNonOperatingIncome"),
+        alias = "other_non_operating_income",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    other_non_operating_income: Option<i64>,
+    #[serde(
+        rename(deserialize = "depreciation"),
+        alias = "depreciation",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    depreciation: Option<i64>,
+    #[serde(
+        rename(deserialize = "depreciationAndAmortization"),
+        alias = "depreciation_and_amortization",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    depreciation_and_amortization: Option<i64>,
+    #[serde(
+        rename(deserialize = "incomeBeforeTax"),
+        alias = "income_before_tax",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    income_before_tax: Option<i64>,
+    #[serde(
+        rename(deserialize = "incomeTaxExpense"),
+        alias = "income_tax_expense",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    income_tax_expense: Option<i64>,
+    #[serde(
+        rename(deserialize = "interestAndDebtExpense"),
+        alias = "interest_and_debt_expense",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    interest_and_debt_expense: Option<i64>,
+    #[serde(
+        rename(deserialize = "netIncomeFromContinuingOperations"),
+        alias = "net_income_from_continuing_operations",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    net_income_from_continuing_operations: Option<i64>,
+    #[serde(
+        rename(deserialize = "comprehensiveIncomeNetOfTax"),
+        alias = "comprehensive_income_net_of_tax",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    comprehensive_income_net_of_tax: Option<i64>,
+    #[serde(
+        rename(deserialize = "ebit"),
+        alias = "ebit",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    ebit: Option<i64>,
+    #[serde(
+        rename(deserialize = "ebitda"),
+        alias = "ebitda",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    ebitda: Option<i64>,
+    #[serde(
+        rename(deserialize = "netIncome"),
+        alias = "net_income",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    net_income: Option<i64>,
+}
+
+impl IncomeStatementReport {
+    /// Return fiscal date ending of report
+    #[must_use]
+    pub fn fiscal_date_ending(&self) -> &str {
+        &self.fiscal_date_ending
+    }
+
+    /// Return currency in which report is reported
+    #[must_use]
+    pub fn reported_currency(&self) -> &str {
+        &self.reported_currency
+    }
+
+    /// Return gross profit. Return None if api return none
+    #[must_use]
+    pub fn gross_profit(&self) -> Option<i64> {
+        self.gross_profit
+    }
+
+    /// Return total revenue. Return None if api return none
+    #[must_use]
+    pub fn total_revenue(&self) -> Option<i64> {
+        self.total_revenue
+    }
+
+    /// Return cost of revenue. Return None if api return none
+    #[must_use]
+    pub fn cost_of_revenue(&self) -> Option<i64> {
+        self.cost_of_revenue
+    }
+
+    /// Return cost of goods and services sold. Return None if api return none
+    #[must_use]
+    pub fn cost_of_goods_and_services_sold(&self) -> Option<i64> {
+        self.cost_of_goods_and_services_sold
+    }
+
+    /// Return operating income. Return None if api return none
+    #[must_use]
+    pub fn operating_income(&self) -> Option<i64> {
+        self.operating_income
+    }
+
+    /// Return selling general and administrative. Return None if api return
+    /// none
+    #[must_use]
+    pub fn selling_general_and_administrative(&self) -> Option<i64> {
+        self.selling_general_and_administrative
+    }
+
+    /// Return research and development. Return None if api return none
+    #[must_use]
+    pub fn research_and_development(&self) -> Option<i64> {
+        self.research_and_development
+    }
+
+    /// Return operating expenses. Return None if api return none
+    #[must_use]
+    pub fn operating_expenses(&self) -> Option<i64> {
+        self.operating_expenses
+    }
+
+    /// Return investment income net. Return None if api return none
+    #[must_use]
+    pub fn investment_income_net(&self) -> Option<i64> {
+        self.investment_income_net
+    }
+
+    /// Return net interest income. Return None if api return none
+    #[must_use]
+    pub fn net_interest_income(&self) -> Option<i64> {
+        self.net_interest_income
+    }
+
+    /// Return interest income. Return None if api return none
+    #[must_use]
+    pub fn interest_income(&self) -> Option<i64> {
+        self.interest_income
+    }
+
+    /// Return interest expense. Return None if api return none
+    #[must_use]
+    pub fn interest_expense(&self) -> Option<i64> {
+        self.interest_expense
+    }
+
+    /// Return non interest income. Return None if api return none
+    #[must_use]
+    pub fn non_interest_income(&self) -> Option<i64> {
+        self.non_interest_income
+    }
+
+    /// Return other non operating income. Return None if api return none
+    #[must_use]
+    pub fn other_non_operating_income(&self) -> Option<i64> {
+        self.other_non_operating_income
+    }
+
+    /// Return depreciation. Return None if api return none
+    #[must_use]
+    pub fn depreciation(&self) -> Option<i64> {
+        self.depreciation
+    }
+
+    /// Return depreciation and amortization. Return None if api return none
+    #[must_use]
+    pub fn depreciation_and_amortization(&self) -> Option<i64> {
+        self.depreciation_and_amortization
+    }
+
+    /// Return income before tax. Return None if api return none
+    #[must_use]
+    pub fn income_before_tax(&self) -> Option<i64> {
+        self.income_before_tax
+    }
+
+    /// Return income tax expense. Return None if api return none
+    #[must_use]
+    pub fn income_tax_expense(&self) -> Option<i64> {
+        self.income_tax_expense
+    }
+
+    /// Return interest and debt expense. Return None if api return none
+    #[must_use]
+    pub fn interest_and_debt_expense(&self) -> Option<i64> {
+        self.interest_and_debt_expense
+    }
+
+    /// Return net income from continuing operations. Return None if api return
+    /// none
+    #[must_use]
+    pub fn net_income_from_continuing_operations(&self) -> Option<i64> {
+        self.net_income_from_continuing_operations
+    }
+
+    /// Return comprehensive income net of tax. Return None if api return none
+    #[must_use]
+    pub fn comprehensive_income_net_of_tax(&self) -> Option<i64> {
+        self.comprehensive_income_net_of_tax
+    }
+
+    /// Return EBIT. Return None if api return none
+    #[must_use]
+    pub fn ebit(&self) -> Option<i64> {
+        self.ebit
+    }
+
+    /// Return EBITDA. Return None if api return none
+    #[must_use]
+    pub fn ebitda(&self) -> Option<i64> {
+        self.ebitda
+    }
+
+    /// Return net income. Return None if api return none
+    #[must_use]
+    pub fn net_income(&self) -> Option<i64> {
+        self.net_income
+    }
+}
+
+/// Struct to store annual or quarterly balance sheet report. Line item missing
+/// from response is stored as None
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct BalanceSheetReport {
+    #[serde(rename(deserialize = "fiscalDateEnding"), alias = "fiscal_date_ending")]
+    fiscal_date_ending: String,
+    #[serde(rename(deserialize = "reportedCurrency"), alias = "reported_currency")]
+    reported_currency: String,
+    #[serde(
+        rename(deserialize = "totalAssets"),
+        alias = "total_assets",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    total_assets: Option<i64>,
+    #[serde(
+        rename(deserialize = "totalCurrentAssets"),
+        alias = "total_current_assets",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    total_current_assets: Option<i64>,
+    #[serde(
+        rename(deserialize = "cashAndCashEquivalentsAtCarryingValue"),
+        alias = "cash_and_cash_equivalents_at_carrying_value",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    cash_and_cash_equivalents_at_carrying_value: Option<i64>,
+    #[serde(
+        rename(deserialize = "cashAndShortTermInvestments"),
+        alias = "cash_and_short_term_investments",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    cash_and_short_term_investments: Option<i64>,
+    #[serde(
+        rename(deserialize = "inventory"),
+        alias = "inventory",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    inventory: Option<i64>,
+    #[serde(
+        rename(deserialize = "currentNetReceivables"),
+        alias = "current_net_receivables",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    current_net_receivables: Option<i64>,
+    #[serde(
+        rename(deserialize = "totalNonCurrentAssets"),
+        alias = "total_non_current_assets",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    total_non_current_assets: Option<i64>,
+    #[serde(
+        rename(deserialize = "propertyPlantEquipment"),
+        alias = "property_plant_equipment",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    property_plant_equipment: Option<i64>,
+    #[serde(
+        rename(deserialize = "accumulatedDepreciationAmortizationPPE"),
+        alias = "accumulated_depreciation_amortization_ppe",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    accumulated_depreciation_amortization_ppe: Option<i64>,
+    #[serde(
+        rename(deserialize = "intangibleAssets"),
+        alias = "intangible_assets",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    intangible_assets: Option<i64>,
+    #[serde(
+        rename(deserialize = "intangibleAssetsExcludingGoodwill"),
+        alias = "intangible_assets_excluding_goodwill",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    intangible_assets_excluding_goodwill: Option<i64>,
+    #[serde(
+        rename(deserialize = "goodwill"),
+        alias = "goodwill",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    goodwill: Option<i64>,
+    #[serde(
+        rename(deserialize = "investments"),
+        alias = "investments",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    investments: Option<i64>,
+    #[serde(
+        rename(deserialize = "longTermInvestments"),
+        alias = "long_term_investments",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    long_term_investments: Option<i64>,
+    #[serde(
+        rename(deserialize = "shortTermInvestments"),
+        alias = "short_term_investments",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    short_term_investments: Option<i64>,
+    #[serde(
+        rename(deserialize = "otherCurrentAssets"),
+        alias = "other_current_assets",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    other_current_assets: Option<i64>,
+    #[serde(
+        rename(deserialize = "otherNonCurrentAssets"),
+        alias = "other_non_current_assets",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    other_non_current_assets: Option<i64>,
+    #[serde(
+        rename(deserialize = "totalLiabilities"),
+        alias = "total_liabilities",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    total_liabilities: Option<i64>,
+    #[serde(
+        rename(deserialize = "totalCurrentLiabilities"),
+        alias = "total_current_liabilities",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    total_current_liabilities: Option<i64>,
+    #[serde(
+        rename(deserialize = "currentAccountsPayable"),
+        alias = "current_accounts_payable",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    current_accounts_payable: Option<i64>,
+    #[serde(
+        rename(deserialize = "deferredRevenue"),
+        alias = "deferred_revenue",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    deferred_revenue: Option<i64>,
+    #[serde(
+        rename(deserialize = "currentDebt"),
+        alias = "current_debt",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    current_debt: Option<i64>,
+    #[serde(
+        rename(deserialize = "shortTermDebt"),
+        alias = "short_term_debt",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    short_term_debt: Option<i64>,
+    #[serde(
+        rename(deserialize = "totalNonCurrentLiabilities"),
+        alias = "total_non_current_liabilities",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    total_non_current_liabilities: Option<i64>,
+    #[serde(
+        rename(deserialize = "capitalLeaseObligations"),
+        alias = "capital_lease_obligations",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    capital_lease_obligations: Option<i64>,
+    #[serde(
+        rename(deserialize = "longTermDebt"),
+        alias = "long_term_debt",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    long_term_debt: Option<i64>,
+    #[serde(
+        rename(deserialize = "currentLongTermDebt"),
+        alias = "current_long_term_debt",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    current_long_term_debt: Option<i64>,
+    #[serde(
+        rename(deserialize = "longTermDebtNoncurrent"),
+        alias = "long_term_debt_noncurrent",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    long_term_debt_noncurrent: Option<i64>,
+    #[serde(
+        rename(deserialize = "shortLongTermDebtTotal"),
+        alias = "short_long_term_debt_total",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    short_long_term_debt_total: Option<i64>,
+    #[serde(
+        rename(deserialize = "otherCurrentLiabilities"),
+        alias = "other_current_liabilities",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    other_current_liabilities: Option<i64>,
+    #[serde(
+        rename(deserialize = "otherNonCurrentLiabilities"),
+        alias = "other_non_current_liabilities",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    other_non_current_liabilities: Option<i64>,
+    #[serde(
+        rename(deserialize = "totalShareholderEquity"),
+        alias = "total_shareholder_equity",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    total_shareholder_equity: Option<i64>,
+    #[serde(
+        rename(deserialize = "treasuryStock"),
+        alias = "treasury_stock",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    treasury_stock: Option<i64>,
+    #[serde(
+        rename(deserialize = "retainedEarnings"),
+        alias = "retained_earnings",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    retained_earnings: Option<i64>,
+    #[serde(
+        rename(deserialize = "commonStock"),
+        alias = "common_stock",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    common_stock: Option<i64>,
+    #[serde(
+        rename(deserialize = "commonStockSharesOutstanding"),
+        alias = "common_stock_shares_outstanding",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    common_stock_shares_outstanding: Option<i64>,
+}
+
+impl BalanceSheetReport {
+    /// Return fiscal date ending of report
+    #[must_use]
+    pub fn fiscal_date_ending(&self) -> &str {
+        &self.fiscal_date_ending
+    }
+
+    /// Return currency in which report is reported
+    #[must_use]
+    pub fn reported_currency(&self) -> &str {
+        &self.reported_currency
+    }
+
+    /// Return total assets. Return None if api return none
+    #[must_use]
+    pub fn total_assets(&self) -> Option<i64> {
+        self.total_assets
+    }
+
+    /// Return total current assets. Return None if api return none
+    #[must_use]
+    pub fn total_current_assets(&self) -> Option<i64> {
+        self.total_current_assets
+    }
+
+    /// Return cash and cash equivalents at carrying value. Return None if api
+    /// return none
+    #[must_use]
+    pub fn cash_and_cash_equivalents_at_carrying_value(&self) -> Option<i64> {
+        self.cash_and_cash_equivalents_at_carrying_value
+    }
+
+    /// Return cash and short term investments. Return None if api return none
+    #[must_use]
+    pub fn cash_and_short_term_investments(&self) -> Option<i64> {
+        self.cash_and_short_term_investments
+    }
+
+    /// Return inventory. Return None if api return none
+    #[must_use]
+    pub fn inventory(&self) -> Option<i64> {
+        self.inventory
+    }
+
+    /// Return current net receivables. Return None if api return none
+    #[must_use]
+    pub fn current_net_receivables(&self) -> Option<i64> {
+        self.current_net_receivables
+    }
+
+    /// Return total non current assets. Return None if api return none
+    #[must_use]
+    pub fn total_non_current_assets(&self) -> Option<i64> {
+        self.total_non_current_assets
+    }
+
+    /// Return property plant equipment. Return None if api return none
+    #[must_use]
+    pub fn property_plant_equipment(&self) -> Option<i64> {
+        self.property_plant_equipment
+    }
+
+    /// Return accumulated depreciation amortization PPE. Return None if api
+    /// return none
+    #[must_use]
+    pub fn accumulated_depreciation_amortization_ppe(&self) -> Option<i64> {
+        self.accumulated_depreciation_amortization_ppe
+    }
+
+    /// Return intangible assets. Return None if api return none
+    #[must_use]
+    pub fn intangible_assets(&self) -> Option<i64> {
+        self.intangible_assets
+    }
+
+    /// Return intangible assets excluding goodwill. Return None if api return
+    /// none
+    #[must_use]
+    pub fn intangible_assets_excluding_goodwill(&self) -> Option<i64> {
+        self.intangible_assets_excluding_goodwill
+    }
+
+    /// Return goodwill. Return None if api return none
+    #[must_use]
+    pub fn goodwill(&self) -> Option<i64> {
+        self.goodwill
+    }
+
+    /// Return investments. Return None if api return none
+    #[must_use]
+    pub fn investments(&self) -> Option<i64> {
+        self.investments
+    }
+
+    /// Return long term investments. Return None if api return none
+    #[must_use]
+    pub fn long_term_investments(&self) -> Option<i64> {
+        self.long_term_investments
+    }
+
+    /// Return short term investments. Return None if api return none
+    #[must_use]
+    pub fn short_term_investments(&self) -> Option<i64> {
+        self.short_term_investments
+    }
+
+    /// Return other current assets. Return None if api return none
+    #[must_use]
+    pub fn other_current_assets(&self) -> Option<i64> {
+        self.other_current_assets
+    }
+
+    /// Return other non current assets. Return None if api return none
+    #[must_use]
+    pub fn other_non_current_assets(&self) -> Option<i64> {
+        self.other_non_current_assets
+    }
+
+    /// Return total liabilities. Return None if api return none
+    #[must_use]
+    pub fn total_liabilities(&self) -> Option<i64> {
+        self.total_liabilities
+    }
+
+    /// Return total current liabilities. Return None if api return none
+    #[must_use]
+    pub fn total_current_liabilities(&self) -> Option<i64> {
+        self.total_current_liabilities
+    }
+
+    /// Return current accounts payable. Return None if api return none
+    #[must_use]
+    pub fn current_accounts_payable(&self) -> Option<i64> {
+        self.current_accounts_payable
+    }
+
+    /// Return deferred revenue. Return None if api return none
+    #[must_use]
+    pub fn deferred_revenue(&self) -> Option<i64> {
+        self.deferred_revenue
+    }
+
+    /// Return current debt. Return None if api return none
+    #[must_use]
+    pub fn current_debt(&self) -> Option<i64> {
+        self.current_debt
+    }
+
+    /// Return short term debt. Return None if api return none
+    #[must_use]
+    pub fn short_term_debt(&self) -> Option<i64> {
+        self.short_term_debt
+    }
+
+    /// Return total non current liabilities. Return None if api return none
+    #[must_use]
+    pub fn total_non_current_liabilities(&self) -> Option<i64> {
+        self.total_non_current_liabilities
+    }
+
+    /// Return capital lease obligations. Return None if api return none
+    #[must_use]
+    pub fn capital_lease_obligations(&self) -> Option<i64> {
+        self.capital_lease_obligations
+    }
+
+    /// Return long term debt. Return None if api return none
+    #[must_use]
+    pub fn long_term_debt(&self) -> Option<i64> {
+        self.long_term_debt
+    }
+
+    /// Return current long term debt. Return None if api return none
+    #[must_use]
+    pub fn current_long_term_debt(&self) -> Option<i64> {
+        self.current_long_term_debt
+    }
+
+    /// Return long term debt noncurrent. Return None if api return none
+    #[must_use]
+    pub fn long_term_debt_noncurrent(&self) -> Option<i64> {
+        self.long_term_debt_noncurrent
+    }
+
+    /// Return short long term debt total. Return None if api return none
+    #[must_use]
+    pub fn short_long_term_debt_total(&self) -> Option<i64> {
+        self.short_long_term_debt_total
+    }
+
+    /// Return other current liabilities. Return None if api return none
+    #[must_use]
+    pub fn other_current_liabilities(&self) -> Option<i64> {
+        self.other_current_liabilities
+    }
+
+    /// Return other non current liabilities. Return None if api return none
+    #[must_use]
+    pub fn other_non_current_liabilities(&self) -> Option<i64> {
+        self.other_non_current_liabilities
+    }
+
+    /// Return total shareholder equity. Return None if api return none
+    #[must_use]
+    pub fn total_shareholder_equity(&self) -> Option<i64> {
+        self.total_shareholder_equity
+    }
+
+    /// Return treasury stock. Return None if api return none
+    #[must_use]
+    pub fn treasury_stock(&self) -> Option<i64> {
+        self.treasury_stock
+    }
+
+    /// Return retained earnings. Return None if api return none
+    #[must_use]
+    pub fn retained_earnings(&self) -> Option<i64> {
+        self.retained_earnings
+    }
+
+    /// Return common stock. Return None if api return none
+    #[must_use]
+    pub fn common_stock(&self) -> Option<i64> {
+        self.common_stock
+    }
+
+    /// Return common stock shares outstanding. Return None if api return none
+    #[must_use]
+    pub fn common_stock_shares_outstanding(&self) -> Option<i64> {
+        self.common_stock_shares_outstanding
+    }
+}
+
+/// Struct to store annual or quarterly cash flow report. Line item missing
+/// from response is stored as None
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct CashFlowReport {
+    #[serde(rename(deserialize = "fiscalDateEnding"), alias = "fiscal_date_ending")]
+    fiscal_date_ending: String,
+    #[serde(rename(deserialize = "reportedCurrency"), alias = "reported_currency")]
+    reported_currency: String,
+    #[serde(
+        rename(deserialize = "operatingCashflow"),
+        alias = "operating_cashflow",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    operating_cashflow: Option<i64>,
+    #[serde(
+        rename(deserialize = "paymentsForOperatingActivities"),
+        alias = "payments_for_operating_activities",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    payments_for_operating_activities: Option<i64>,
+    #[serde(
+        rename(deserialize = "proceedsFromOperatingActivities"),
+        alias = "proceeds_from_operating_activities",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    proceeds_from_operating_activities: Option<i64>,
+    #[serde(
+        rename(deserialize = "changeInOperatingLiabilities"),
+        alias = "change_in_operating_liabilities",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    change_in_operating_liabilities: Option<i64>,
+    #[serde(
+        rename(deserialize = "changeInOperatingAssets"),
+        alias = "change_in_operating_assets",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    change_in_operating_assets: Option<i64>,
+    #[serde(
+        rename(deserialize = "depreciationDepletionAndAmortization"),
+        alias = "depreciation_depletion_and_amortization",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    depreciation_depletion_and_amortization: Option<i64>,
+    #[serde(
+        rename(deserialize = "capitalExpenditures"),
+        alias = "capital_expenditures",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    capital_expenditures: Option<i64>,
+    #[serde(
+        rename(deserialize = "changeInReceivables"),
+        alias = "change_in_receivables",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    change_in_receivables: Option<i64>,
+    #[serde(
+        rename(deserialize = "changeInInventory"),
+        alias = "change_in_inventory",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    change_in_inventory: Option<i64>,
+    #[serde(
+        rename(deserialize = "profitLoss"),
+        alias = "profit_loss",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    profit_loss: Option<i64>,
+    #[serde(
+        rename(deserialize = "cashflowFromInvestment"),
+        alias = "cashflow_from_investment",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    cashflow_from_investment: Option<i64>,
+    #[serde(
+        rename(deserialize = "cashflowFromFinancing"),
+        alias = "cashflow_from_financing",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    cashflow_from_financing: Option<i64>,
+    #[serde(
+        rename(deserialize = "proceedsFromRepaymentsOfShortTermDebt"),
+        alias = "proceeds_from_repayments_of_short_term_debt",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    proceeds_from_repayments_of_short_term_debt: Option<i64>,
+    #[serde(
+        rename(deserialize = "paymentsForRepurchaseOfCommonStock"),
+        alias = "payments_for_repurchase_of_common_stock",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    payments_for_repurchase_of_common_stock: Option<i64>,
+    #[serde(
+        rename(deserialize = "paymentsForRepurchaseOfEquity"),
+        alias = "payments_for_repurchase_of_equity",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    payments_for_repurchase_of_equity: Option<i64>,
+    #[serde(
+        rename(deserialize = "paymentsForRepurchaseOfPreferredStock"),
+        alias = "payments_for_repurchase_of_preferred_stock",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    payments_for_repurchase_of_preferred_stock: Option<i64>,
+    #[serde(
+        rename(deserialize = "dividendPayout"),
+        alias = "dividend_payout",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    dividend_payout: Option<i64>,
+    #[serde(
+        rename(deserialize = "dividendPayoutCommonStock"),
+        alias = "dividend_payout_common_stock",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    dividend_payout_common_stock: Option<i64>,
+    #[serde(
+        rename(deserialize = "dividendPayoutPreferredStock"),
+        alias = "dividend_payout_preferred_stock",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    dividend_payout_preferred_stock: Option<i64>,
+    #[serde(
+        rename(deserialize = "proceedsFromIssuanceOfCommonStock"),
+        alias = "proceeds_from_issuance_of_common_stock",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    proceeds_from_issuance_of_common_stock: Option<i64>,
+    #[serde(
+        rename(deserialize = "proceedsFromIssuanceOfLongTermDebtAndCapitalSecuritiesNet"),
+        alias = "proceeds_from_issuance_of_long_term_debt_and_capital_securities_net",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    proceeds_from_issuance_of_long_term_debt_and_capital_securities_net: Option<i64>,
+    #[serde(
+        rename(deserialize = "proceedsFromIssuanceOfPreferredStock"),
+        alias = "proceeds_from_issuance_of_preferred_stock",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    proceeds_from_issuance_of_preferred_stock: Option<i64>,
+    #[serde(
+        rename(deserialize = "proceedsFromRepurchaseOfEquity"),
+        alias = "proceeds_from_repurchase_of_equity",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    proceeds_from_repurchase_of_equity: Option<i64>,
+    #[serde(
+        rename(deserialize = "proceedsFromSaleOfTreasuryStock"),
+        alias = "proceeds_from_sale_of_treasury_stock",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    proceeds_from_sale_of_treasury_stock: Option<i64>,
+    #[serde(
+        rename(deserialize = "changeInCashAndCashEquivalents"),
+        alias = "change_in_cash_and_cash_equivalents",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    change_in_cash_and_cash_equivalents: Option<i64>,
+    #[serde(
+        rename(deserialize = "changeInExchangeRate"),
+        alias = "change_in_exchange_rate",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    change_in_exchange_rate: Option<i64>,
+    #[serde(
+        rename(deserialize = "netIncome"),
+        alias = "net_income",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    net_income: Option<i64>,
+}
+
+impl CashFlowReport {
+    /// Return fiscal date ending of report
+    #[must_use]
+    pub fn fiscal_date_ending(&self) -> &str {
+        &self.fiscal_date_ending
+    }
+
+    /// Return currency in which report is reported
+    #[must_use]
+    pub fn reported_currency(&self) -> &str {
+        &self.reported_currency
+    }
+
+    /// Return operating cashflow. Return None if api return none
+    #[must_use]
+    pub fn operating_cashflow(&self) -> Option<i64> {
+        self.operating_cashflow
+    }
+
+    /// Return payments for operating activities. Return None if api return none
+    #[must_use]
+    pub fn payments_for_operating_activities(&self) -> Option<i64> {
+        self.payments_for_operating_activities
+    }
+
+    /// Return proceeds from operating activities. Return None if api return
+    /// none
+    #[must_use]
+    pub fn proceeds_from_operating_activities(&self) -> Option<i64> {
+        self.proceeds_from_operating_activities
+    }
+
+    /// Return change in operating liabilities. Return None if api return none
+    #[must_use]
+    pub fn change_in_operating_liabilities(&self) -> Option<i64> {
+        self.change_in_operating_liabilities
+    }
+
+    /// Return change in operating assets. Return None if api return none
+    #[must_use]
+    pub fn change_in_operating_assets(&self) -> Option<i64> {
+        self.change_in_operating_assets
+    }
+
+    /// Return depreciation depletion and amortization. Return None if api
+    /// return none
+    #[must_use]
+    pub fn depreciation_depletion_and_amortization(&self) -> Option<i64> {
+        self.depreciation_depletion_and_amortization
+    }
+
+    /// Return capital expenditures. Return None if api return none
+    #[must_use]
+    pub fn capital_expenditures(&self) -> Option<i64> {
+        self.capital_expenditures
+    }
+
+    /// Return change in receivables. Return None if api return none
+    #[must_use]
+    pub fn change_in_receivables(&self) -> Option<i64> {
+        self.change_in_receivables
+    }
+
+    /// Return change in inventory. Return None if api return none
+    #[must_use]
+    pub fn change_in_inventory(&self) -> Option<i64> {
+        self.change_in_inventory
+    }
+
+    /// Return profit loss. Return None if api return none
+    #[must_use]
+    pub fn profit_loss(&self) -> Option<i64> {
+        self.profit_loss
+    }
+
+    /// Return cashflow from investment. Return None if api return none
+    #[must_use]
+    pub fn cashflow_from_investment(&self) -> Option<i64> {
+        self.cashflow_from_investment
+    }
+
+    /// Return cashflow from financing. Return None if api return none
+    #[must_use]
+    pub fn cashflow_from_financing(&self) -> Option<i64> {
+        self.cashflow_from_financing
+    }
+
+    /// Return proceeds from repayments of short term debt. Return None if api
+    /// return none
+    #[must_use]
+    pub fn proceeds_from_repayments_of_short_term_debt(&self) -> Option<i64> {
+        self.proceeds_from_repayments_of_short_term_debt
+    }
+
+    /// Return payments for repurchase of common stock. Return None if api
+    /// return none
+    #[must_use]
+    pub fn payments_for_repurchase_of_common_stock(&self) -> Option<i64> {
+        self.payments_for_repurchase_of_common_stock
+    }
+
+    /// Return payments for repurchase of equity. Return None if api return none
+    #[must_use]
+    pub fn payments_for_repurchase_of_equity(&self) -> Option<i64> {
+        self.payments_for_repurchase_of_equity
+    }
+
+    /// Return payments for repurchase of preferred stock. Return None if api
+    /// return none
+    #[must_use]
+    pub fn payments_for_repurchase_of_preferred_stock(&self) -> Option<i64> {
+        self.payments_for_repurchase_of_preferred_stock
+    }
+
+    /// Return dividend payout. Return None if api return none
+    #[must_use]
+    pub fn dividend_payout(&self) -> Option<i64> {
+        self.dividend_payout
+    }
+
+    /// Return dividend payout common stock. Return None if api return none
+    #[must_use]
+    pub fn dividend_payout_common_stock(&self) -> Option<i64> {
+        self.dividend_payout_common_stock
+    }
+
+    /// Return dividend payout preferred stock. Return None if api return none
+    #[must_use]
+    pub fn dividend_payout_preferred_stock(&self) -> Option<i64> {
+        self.dividend_payout_preferred_stock
+    }
+
+    /// Return proceeds from issuance of common stock. Return None if api return
+    /// none
+    #[must_use]
+    pub fn proceeds_from_issuance_of_common_stock(&self) -> Option<i64> {
+        self.proceeds_from_issuance_of_common_stock
+    }
+
+    /// Return proceeds from issuance of long term debt and capital securities
+    /// net. Return None if api return none
+    #[must_use]
+    pub fn proceeds_from_issuance_of_long_term_debt_and_capital_securities_net(
+        &self,
+    ) -> Option<i64> {
+        self.proceeds_from_issuance_of_long_term_debt_and_capital_securities_net
+    }
+
+    /// Return proceeds from issuance of preferred stock. Return None if api
+    /// return none
+    #[must_use]
+    pub fn proceeds_from_issuance_of_preferred_stock(&self) -> Option<i64> {
+        self.proceeds_from_issuance_of_preferred_stock
+    }
+
+    /// Return proceeds from repurchase of equity. Return None if api return
+    /// none
+    #[must_use]
+    pub fn proceeds_from_repurchase_of_equity(&self) -> Option<i64> {
+        self.proceeds_from_repurchase_of_equity
+    }
+
+    /// Return proceeds from sale of treasury stock. Return None if api return
+    /// none
+    #[must_use]
+    pub fn proceeds_from_sale_of_treasury_stock(&self) -> Option<i64> {
+        self.proceeds_from_sale_of_treasury_stock
+    }
+
+    /// Return change in cash and cash equivalents. Return None if api return
+    /// none
+    #[must_use]
+    pub fn change_in_cash_and_cash_equivalents(&self) -> Option<i64> {
+        self.change_in_cash_and_cash_equivalents
+    }
+
+    /// Return change in exchange rate. Return None if api return none
+    #[must_use]
+    pub fn change_in_exchange_rate(&self) -> Option<i64> {
+        self.change_in_exchange_rate
+    }
+
+    /// Return net income. Return None if api return none
+    #[must_use]
+    pub fn net_income(&self) -> Option<i64> {
+        self.net_income
+    }
+}
+
+/// Struct to store financial statement of symbol
+///
+/// Serialized as object with `symbol`, `annual_reports` and
+/// `quarterly_reports` containing list of report object with
+/// `fiscal_date_ending`, `reported_currency` and snake case name of each line
+/// item. Line item which is not available is serialized as null
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Statement<T> {
+    symbol: String,
+    annual_reports: Vec<T>,
+    quarterly_reports: Vec<T>,
+}
+
+impl<T> Statement<T> {
+    /// Return symbol of company
+    #[must_use]
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Return annual reports of symbol
+    ///
+    /// # Example
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let income_statement = api.income_statement("IBM").json().await.unwrap();
+    ///     let report = &income_statement.annual_reports()[0];
+    ///     assert_eq!(report.fiscal_date_ending(), "2022-12-31");
+    ///     assert_eq!(report.reported_currency(), "USD");
+    ///     assert_eq!(report.total_revenue(), Some(60_530_000_000));
+    ///     assert_eq!(report.investment_income_net(), None);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn annual_reports(&self) -> &Vec<T> {
+        &self.annual_reports
+    }
+
+    /// Return quarterly reports of symbol
+    #[must_use]
+    pub fn quarterly_reports(&self) -> &Vec<T> {
+        &self.quarterly_reports
+    }
+}
+
+/// Income statement of symbol
+pub type IncomeStatement = Statement<IncomeStatementReport>;
+
+/// Balance sheet of symbol
+pub type BalanceSheet = Statement<BalanceSheetReport>;
+
+/// Cash flow of symbol
+pub type CashFlow = Statement<CashFlowReport>;
+
+/// Struct used for creating financial statement
+#[derive(Debug, Deserialize)]
+pub(crate) struct StatementHelper<T> {
+    #[serde(rename = "Information")]
+    information: Option<String>,
+    #[serde(rename = "Error Message")]
+    error_message: Option<String>,
+    #[serde(rename = "Note")]
+    note: Option<String>,
+    symbol: Option<String>,
+    #[serde(rename = "annualReports")]
+    annual_reports: Option<Vec<T>>,
+    #[serde(rename = "quarterlyReports")]
+    quarterly_reports: Option<Vec<T>>,
+}
+
+impl<T> StatementHelper<T> {
+    /// Function which convert `StatementHelper` to `Statement`
+    fn convert(self) -> Result<Statement<T>> {
+        detect_common_helper_error(self.information, self.error_message, self.note)?;
+        let (Some(symbol), Some(annual_reports), Some(quarterly_reports)) =
+            (self.symbol, self.annual_reports, self.quarterly_reports)
+        else {
+            return Err(Error::EmptyResponse);
+        };
+        Ok(Statement {
+            symbol,
+            annual_reports,
+            quarterly_reports,
+        })
+    }
+}
+
+type IncomeStatementHelper = StatementHelper<IncomeStatementReport>;
+
+type BalanceSheetHelper = StatementHelper<BalanceSheetReport>;
+
+type CashFlowHelper = StatementHelper<CashFlowReport>;
+
+/// Builder to help create `IncomeStatement`
+pub struct IncomeStatementBuilder<'a> {
+    api_client: &'a ApiClient,
+    symbol: &'a str,
+}
+
+impl<'a> IncomeStatementBuilder<'a> {
+    crate::json_data_struct!(IncomeStatement, IncomeStatementHelper);
+
+    /// Create new `IncomeStatementBuilder` with help of `APIClient`
+    #[must_use]
+    pub fn new(api_client: &'a ApiClient, symbol: &'a str) -> Self {
+        Self { api_client, symbol }
+    }
+
+    fn create_url(&self) -> String {
+        format!("query?function=INCOME_STATEMENT&symbol={}", self.symbol)
+    }
+}
+
+/// Builder to help create `BalanceSheet`
+pub struct BalanceSheetBuilder<'a> {
+    api_client: &'a ApiClient,
+    symbol: &'a str,
+}
+
+impl<'a> BalanceSheetBuilder<'a> {
+    crate::json_data_struct!(BalanceSheet, BalanceSheetHelper);
+
+    /// Create new `BalanceSheetBuilder` with help of `APIClient`
+    #[must_use]
+    pub fn new(api_client: &'a ApiClient, symbol: &'a str) -> Self {
+        Self { api_client, symbol }
+    }
+
+    fn create_url(&self) -> String {
+        format!("query?function=BALANCE_SHEET&symbol={}", self.symbol)
+    }
+}
+
+/// Builder to help create `CashFlow`
+pub struct CashFlowBuilder<'a> {
+    api_client: &'a ApiClient,
+    symbol: &'a str,
+}
+
+impl<'a> CashFlowBuilder<'a> {
+    crate::json_data_struct!(CashFlow, CashFlowHelper);
+
+    /// Create new `CashFlowBuilder` with help of `APIClient`
+    #[must_use]
+    pub fn new(api_client: &'a ApiClient, symbol: &'a str) -> Self {
+        Self { api_client, symbol }
+    }
+
+    fn create_url(&self) -> String {
+        format!("query?function=CASH_FLOW&symbol={}", self.symbol)
+    }
+}
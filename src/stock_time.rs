@@ -10,19 +10,19 @@
 //!
 //! [stock_time]: https://www.alphavantage.co/documentation/#time-series-data
 
-use std::cmp;
 use std::collections::HashMap;
-use std::str::FromStr;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::api::{ApiClient, OutputSize, TimeSeriesInterval};
-use crate::deserialize::from_str;
+use crate::candle::Candle;
+use crate::deserialize::{from_str, meta_value, option_from_str, Price};
+#[cfg(feature = "chrono")]
+use crate::deserialize::{parse_datetime, parse_time_zone};
 use crate::error::{detect_common_helper_error, Error, Result};
-use crate::vec_trait::FindData;
 
 /// Struct for storing Meta Data value
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MetaData {
     information: String,
     symbol: String,
@@ -33,17 +33,20 @@ pub struct MetaData {
 }
 
 /// Struct for Data value
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct Data {
     time: String,
-    open: f64,
-    high: f64,
-    low: f64,
-    close: f64,
-    adjusted_close: Option<f64>,
+    open: Price,
+    high: Price,
+    low: Price,
+    close: Price,
+    adjusted_close: Option<Price>,
     volume: u64,
     dividend_amount: Option<f64>,
     split_coefficient: Option<f64>,
+    #[cfg(feature = "chrono")]
+    #[serde(skip)]
+    time_zone: String,
 }
 
 impl Data {
@@ -53,34 +56,133 @@ impl Data {
         &self.time
     }
 
+    /// Return time as date time in time zone of meta data
+    ///
+    /// ```
+    /// use alpha_vantage::chrono::Timelike;
+    /// use alpha_vantage::vec_trait::FindData;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let stock = api
+    ///         .stock_time(alpha_vantage::stock_time::StockFunction::IntraDay, "MSFT")
+    ///         .interval(alpha_vantage::api::TimeSeriesInterval::FiveMin)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     let datetime = stock.data().latest().datetime().unwrap();
+    ///     assert_eq!(datetime.timezone(), alpha_vantage::chrono_tz::US::Eastern);
+    ///     assert_eq!(datetime.hour(), 19);
+    /// }
+    /// ```
+    ///
+    /// Time zone is parsed only when date time is requested so response with
+    /// unknown time zone can still be decoded
+    ///
+    /// ```
+    /// use alpha_vantage::error::Error;
+    /// use alpha_vantage::mock::MockClient;
+    /// use alpha_vantage::vec_trait::FindData;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = MockClient::new().response(
+    ///         "function=TIME_SERIES_WEEKLY",
+    ///         r#"{"Meta Data": {"1. Information": "Weekly Prices (open, high, low, close) and Volumes",
+    ///             "2. Symbol": "MSFT", "3. Last Refreshed": "2024-01-02", "4. Time Zone": "Mars/Olympus"},
+    ///             "Weekly Time Series": {"2024-01-02": {"1. open": "1", "2. high": "1", "3. low": "1",
+    ///             "4. close": "1", "5. volume": "1"}}}"#,
+    ///     );
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let stock = api
+    ///         .stock_time(alpha_vantage::stock_time::StockFunction::Weekly, "MSFT")
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     assert_eq!(stock.time_zone(), "Mars/Olympus");
+    ///     assert!(matches!(
+    ///         stock.data().latest().datetime(),
+    ///         Err(Error::AlphaVantageInvalidData(_))
+    ///     ));
+    /// }
+    /// ```
+    /// # Errors
+    /// Raise error if time or time zone of meta data cannot be parsed
+    #[cfg(feature = "chrono")]
+    pub fn datetime(&self) -> Result<chrono::DateTime<chrono_tz::Tz>> {
+        parse_datetime(&self.time, parse_time_zone(&self.time_zone)?)
+    }
+
     /// Return open
     #[must_use]
     pub fn open(&self) -> f64 {
-        self.open
+        self.open.value
+    }
+
+    /// Return open as exact decimal. Return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn open_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.open.decimal
     }
 
     /// Return high
     #[must_use]
     pub fn high(&self) -> f64 {
-        self.high
+        self.high.value
+    }
+
+    /// Return high as exact decimal. Return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn high_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.high.decimal
     }
 
     /// Return low
     #[must_use]
     pub fn low(&self) -> f64 {
-        self.low
+        self.low.value
+    }
+
+    /// Return low as exact decimal. Return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn low_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.low.decimal
     }
 
     /// Return close
     #[must_use]
     pub fn close(&self) -> f64 {
-        self.close
+        self.close.value
+    }
+
+    /// Return close as exact decimal. Return None if value
+    /// cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn close_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.close.decimal
     }
 
     /// Return adjusted
     #[must_use]
     pub fn adjusted(&self) -> Option<f64> {
-        self.adjusted_close
+        self.adjusted_close.map(|price| price.value)
+    }
+
+    /// Return adjusted close as exact decimal. Return None if adjusted close is
+    /// not present or cannot be represented as decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn adjusted_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.adjusted_close.and_then(|price| price.decimal)
     }
 
     /// Return volume
@@ -103,19 +205,111 @@ impl Data {
 }
 
 /// Struct for storing time series data
-#[derive(Debug, Default)]
+///
+/// Serialized as object with `meta_data` containing `information`, `symbol`,
+/// `last_refreshed`, `interval`, `output_size` and `time_zone` and `data`
+/// containing list of object with `time`, `open`, `high`, `low`, `close`,
+/// `adjusted_close`, `volume`, `dividend_amount` and `split_coefficient`
+///
+/// ```
+/// #[tokio::main]
+/// async fn main() {
+///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+///     let api = alpha_vantage::set_api("demo", client);
+///     let stock = api
+///         .stock_time(alpha_vantage::stock_time::StockFunction::Weekly, "MSFT")
+///         .json()
+///         .await
+///         .unwrap();
+///     let json = serde_json::to_value(&stock).unwrap();
+///     assert_eq!(json["meta_data"]["symbol"], "MSFT");
+///     let restored: alpha_vantage::stock_time::TimeSeries = serde_json::from_value(json).unwrap();
+///     assert_eq!(restored.data().len(), stock.data().len());
+/// }
+/// ```
+///
+/// Decoding returns `Error::AlphaVantageInvalidData` if meta data is missing or
+/// adjusted value cannot be parsed
+///
+/// ```
+/// use alpha_vantage::error::Error;
+/// use alpha_vantage::mock::MockClient;
+/// use alpha_vantage::stock_time::StockFunction;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = MockClient::new().response(
+///         "function=TIME_SERIES_INTRADAY",
+///         r#"{"Meta Data": {"1. Information": "Intraday", "2. Symbol": "MSFT", "3. Last Refreshed": "2024-01-02 16:00:00"},
+///             "Time Series (5min)": {}}"#,
+///     );
+///     let api = alpha_vantage::set_api("demo", client);
+///     let error = api
+///         .stock_time(StockFunction::IntraDay, "MSFT")
+///         .interval(alpha_vantage::api::TimeSeriesInterval::FiveMin)
+///         .json()
+///         .await
+///         .err()
+///         .unwrap();
+///     assert!(matches!(error, Error::AlphaVantageInvalidData(_)));
+///     assert!(error.to_string().contains("Time Zone"));
+///
+///     let client = MockClient::new().response(
+///         "function=TIME_SERIES_DAILY_ADJUSTED",
+///         r#"{"Meta Data": {"1. Information": "Daily", "2. Symbol": "MSFT",
+///             "3. Last Refreshed": "2024-01-02", "4. Output Size": "Compact", "5. Time Zone": "US/Eastern"},
+///             "Time Series (Daily)": {"2024-01-02": {"1. open": "1", "2. high": "1", "3. low": "1",
+///             "4. close": "1", "5. adjusted close": "abc", "6. volume": "1",
+///             "7. dividend amount": "0", "8. split coefficient": "1"}}}"#,
+///     );
+///     let api = alpha_vantage::set_api("demo", client);
+///     let error = api
+///         .stock_time(StockFunction::DailyAdjusted, "MSFT")
+///         .json()
+///         .await
+///         .err()
+///         .unwrap();
+///     assert!(matches!(error, Error::AlphaVantageInvalidData(_)));
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(from = "TimeSeriesRecord")]
 pub struct TimeSeries {
     meta_data: MetaData,
     data: Vec<Data>,
 }
 
+/// Normalized representation of `TimeSeries` used for sorting data and
+/// restoring time zone of data while deserializing
+#[derive(Deserialize)]
+struct TimeSeriesRecord {
+    meta_data: MetaData,
+    data: Vec<Data>,
+}
+
+impl From<TimeSeriesRecord> for TimeSeries {
+    fn from(record: TimeSeriesRecord) -> Self {
+        let mut data = record.data;
+        #[cfg(feature = "chrono")]
+        for value in &mut data {
+            value.time_zone.clone_from(&record.meta_data.time_zone);
+        }
+        data.sort_by(|first, second| first.time.cmp(&second.time));
+        Self {
+            meta_data: record.meta_data,
+            data,
+        }
+    }
+}
+
 impl TimeSeries {
     /// Return information present in meta data
     ///
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let stock_time = api
     ///         .stock_time(alpha_vantage::stock_time::StockFunction::IntraDay, "MSFT")
     ///         .interval(alpha_vantage::api::TimeSeriesInterval::FiveMin)
@@ -140,7 +334,8 @@ impl TimeSeries {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let stock_time = api
     ///         .stock_time(alpha_vantage::stock_time::StockFunction::IntraDay, "MSFT")
     ///         .interval(alpha_vantage::api::TimeSeriesInterval::FiveMin)
@@ -174,7 +369,8 @@ impl TimeSeries {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let stock_time = api
     ///         .stock_time(alpha_vantage::stock_time::StockFunction::IntraDay, "MSFT")
     ///         .interval(alpha_vantage::api::TimeSeriesInterval::FiveMin)
@@ -196,7 +392,8 @@ impl TimeSeries {
     /// ```
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
     ///     let stock_time = api
     ///         .stock_time(alpha_vantage::stock_time::StockFunction::IntraDay, "MSFT")
     ///         .interval(alpha_vantage::api::TimeSeriesInterval::FiveMin)
@@ -213,7 +410,7 @@ impl TimeSeries {
         self.operate_option_meta_value("output size")
     }
 
-    /// Return Data
+    /// Return Data sorted chronologically
     #[must_use]
     pub fn data(&self) -> &Vec<Data> {
         &self.data
@@ -245,13 +442,13 @@ impl TimeSeries {
 #[derive(Clone, Deserialize)]
 struct DataHelper {
     #[serde(rename = "1. open", deserialize_with = "from_str")]
-    open: f64,
+    open: Price,
     #[serde(rename = "2. high", deserialize_with = "from_str")]
-    high: f64,
+    high: Price,
     #[serde(rename = "3. low", deserialize_with = "from_str")]
-    low: f64,
+    low: Price,
     #[serde(rename = "4. close", deserialize_with = "from_str")]
-    close: f64,
+    close: Price,
     #[serde(rename = "5. volume", deserialize_with = "from_str")]
     volume: u64,
 }
@@ -260,13 +457,13 @@ struct DataHelper {
 #[derive(Deserialize, Clone)]
 struct AdjustedHelper {
     #[serde(rename = "1. open", deserialize_with = "from_str")]
-    open: f64,
+    open: Price,
     #[serde(rename = "2. high", deserialize_with = "from_str")]
-    high: f64,
+    high: Price,
     #[serde(rename = "3. low", deserialize_with = "from_str")]
-    low: f64,
+    low: Price,
     #[serde(rename = "4. close", deserialize_with = "from_str")]
-    close: f64,
+    close: Price,
     #[serde(rename = "5. adjusted close")]
     adjusted_close: Option<String>,
     #[serde(rename = "6. volume", deserialize_with = "from_str")]
@@ -295,20 +492,22 @@ pub(crate) struct TimeSeriesHelper {
 }
 
 impl TimeSeriesHelper {
-    /// Convert `TimeSeriesHelper` to `TimeSeries`
+    /// Convert `TimeSeriesHelper` to `TimeSeries`. Return
+    /// `Error::AlphaVantageInvalidData` if meta data is missing or adjusted
+    /// value cannot be parsed
     fn convert(self) -> Result<TimeSeries> {
         detect_common_helper_error(self.information, self.error_message, self.note)?;
 
-        if self.meta_data.is_none()
-            || (self.time_series.is_none() && self.adjusted_series.is_none())
-        {
+        let Some(meta_data) = self.meta_data else {
+            return Err(Error::EmptyResponse);
+        };
+        if self.time_series.is_none() && self.adjusted_series.is_none() {
             return Err(Error::EmptyResponse);
         }
 
-        let meta_data = self.meta_data.unwrap();
-        let information = &meta_data["1. Information"];
-        let symbol = &meta_data["2. Symbol"];
-        let last_refreshed = &meta_data["3. Last Refreshed"];
+        let information = meta_value(&meta_data, &["1. Information"])?;
+        let symbol = meta_value(&meta_data, &["2. Symbol"])?;
+        let last_refreshed = meta_value(&meta_data, &["3. Last Refreshed"])?;
         let interval = meta_data.get("4. Interval");
 
         let mut output_size = meta_data.get("4. Output Size");
@@ -316,39 +515,34 @@ impl TimeSeriesHelper {
             output_size = meta_data.get("5. Output Size");
         }
 
-        let time_zone = meta_data.get("4. Time Zone").unwrap_or_else(|| {
-            meta_data.get("5. Time Zone").unwrap_or_else(|| {
-                meta_data
-                    .get("6. Time Zone")
-                    .expect("time zone value is None")
-            })
-        });
+        let time_zone = meta_value(
+            &meta_data,
+            &["4. Time Zone", "5. Time Zone", "6. Time Zone"],
+        )?;
 
         let meta_data = MetaData {
-            information: information.to_string(),
-            symbol: symbol.to_string(),
-            last_refreshed: last_refreshed.to_string(),
+            information: information.clone(),
+            symbol: symbol.clone(),
+            last_refreshed: last_refreshed.clone(),
             interval: interval.map(ToString::to_string),
             output_size: output_size.map(ToString::to_string),
-            time_zone: time_zone.to_string(),
+            time_zone: time_zone.clone(),
         };
 
         let mut data_value: Vec<Data> = Vec::new();
 
         if let Some(time_series) = self.time_series {
             for hash in time_series.values() {
-                for val in hash.keys() {
-                    let data_helper = hash
-                        .get(val)
-                        .expect("failed to get value from stock time hashmap");
-
+                for (val, data_helper) in hash {
                     data_value.push(Data {
-                        time: val.to_string(),
+                        time: val.clone(),
                         open: data_helper.open,
                         high: data_helper.high,
                         low: data_helper.low,
                         close: data_helper.close,
                         volume: data_helper.volume,
+                        #[cfg(feature = "chrono")]
+                        time_zone: time_zone.clone(),
                         ..Data::default()
                     });
                 }
@@ -357,26 +551,34 @@ impl TimeSeriesHelper {
 
         if let Some(adjusted_series) = self.adjusted_series {
             for hash in adjusted_series.values() {
-                for val in hash.keys() {
-                    let data_helper = hash
-                        .get(val)
-                        .expect("failed to get value from adjusted series");
-
+                for (val, data_helper) in hash {
                     data_value.push(Data {
-                        time: val.to_string(),
+                        time: val.clone(),
                         open: data_helper.open,
                         high: data_helper.high,
                         low: data_helper.low,
                         close: data_helper.close,
                         volume: data_helper.volume,
-                        adjusted_close: option_from_str(&data_helper.adjusted_close),
-                        split_coefficient: option_from_str(&data_helper.split_coefficient),
-                        dividend_amount: option_from_str(&data_helper.dividend_amount),
+                        adjusted_close: option_from_str(
+                            data_helper.adjusted_close.as_ref(),
+                            "adjusted close",
+                        )?,
+                        split_coefficient: option_from_str(
+                            data_helper.split_coefficient.as_ref(),
+                            "split coefficient",
+                        )?,
+                        dividend_amount: option_from_str(
+                            data_helper.dividend_amount.as_ref(),
+                            "dividend amount",
+                        )?,
+                        #[cfg(feature = "chrono")]
+                        time_zone: time_zone.clone(),
                     });
                 }
             }
         }
 
+        data_value.sort_by(|first, second| first.time.cmp(&second.time));
         Ok(TimeSeries {
             data: data_value,
             meta_data,
@@ -384,48 +586,31 @@ impl TimeSeriesHelper {
     }
 }
 
-impl FindData for Vec<Data> {
-    #[must_use]
-    fn find(&self, time: &str) -> Option<&<Self as IntoIterator>::Item> {
-        self.iter().find(|&data| data.time == time)
+impl Candle for Data {
+    fn time(&self) -> &str {
+        &self.time
     }
 
-    #[must_use]
-    fn latest(&self) -> <Self as IntoIterator>::Item {
-        let mut latest = &Data::default();
-        for data in self {
-            if latest.time < data.time {
-                latest = data;
-            }
-        }
-        latest.clone()
+    fn open(&self) -> f64 {
+        self.open.value
     }
 
-    fn latest_n(&self, n: usize) -> Result<Vec<&<Self as IntoIterator>::Item>> {
-        let mut time_list = self.iter().map(|data| &data.time).collect::<Vec<_>>();
-        time_list.sort_by_key(|w| cmp::Reverse(*w));
-
-        if n > time_list.len() {
-            return Err(Error::DesiredNumberOfDataNotPresent(time_list.len()));
-        }
-
-        let mut full_list = Vec::<&Data>::new();
+    fn high(&self) -> f64 {
+        self.high.value
+    }
 
-        for time in &time_list[0..n] {
-            full_list.push(self.find(time).unwrap());
-        }
+    fn low(&self) -> f64 {
+        self.low.value
+    }
 
-        Ok(full_list)
+    fn close(&self) -> f64 {
+        self.close.value
     }
-}
 
-// convert string to optional T
-fn option_from_str<T>(val: &Option<String>) -> Option<T>
-where
-    T: FromStr,
-    T::Err: std::error::Error,
-{
-    val.as_ref().map(|s| T::from_str(s).unwrap())
+    #[allow(clippy::cast_precision_loss)]
+    fn volume(&self) -> Option<f64> {
+        Some(self.volume as f64)
+    }
 }
 
 /// Builder to create new `TimeSeries`
@@ -497,15 +682,17 @@ impl<'a> TimeSeriesBuilder<'a> {
                 TimeSeriesInterval::ThirtyMin => "30min",
                 TimeSeriesInterval::SixtyMin => "60min",
             };
-            url.push_str(&format!("&interval={interval}"));
-        };
+            url.push_str("&interval=");
+            url.push_str(interval);
+        }
 
         if let Some(stock_time_output_size) = &self.output_size {
             let size = match stock_time_output_size {
                 OutputSize::Full => "full",
                 OutputSize::Compact => "compact",
             };
-            url.push_str(&format!("&outputsize={size}"));
+            url.push_str("&outputsize=");
+            url.push_str(size);
         }
 
         if let Some(adjusted) = self.adjusted {
@@ -514,7 +701,7 @@ impl<'a> TimeSeriesBuilder<'a> {
             } else {
                 url.push_str("&adjusted=false");
             }
-        };
+        }
 
         url
     }
@@ -10,8 +10,9 @@
 //! [technical_indicator]: https://www.alphavantage.co/documentation/#technical-indicators
 
 use std::collections::HashMap;
+use std::fmt::Write;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize, Serializer};
 use serde_json::value::Value;
 
 use crate::api::ApiClient;
@@ -20,13 +21,21 @@ use crate::error::{detect_common_helper_error, Error, Result};
 type DataType = HashMap<String, HashMap<String, HashMap<String, String>>>;
 
 /// Struct for storing a data values
-#[derive(Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DataCollector {
     time: String,
     values: HashMap<String, f64>,
 }
 
 impl DataCollector {
+    /// Create new data collector for time with values
+    pub(crate) fn new(time: &str, values: HashMap<String, f64>) -> Self {
+        Self {
+            time: time.to_string(),
+            values,
+        }
+    }
+
     /// Return time
     #[must_use]
     pub fn time(&self) -> &str {
@@ -38,15 +47,408 @@ impl DataCollector {
     pub fn values(&self) -> &HashMap<String, f64> {
         &self.values
     }
+
+    /// Return value for key or error if value is not present
+    fn required_value(&self, key: &str) -> Result<f64> {
+        self.values.get(key).copied().ok_or_else(|| {
+            Error::AlphaVantageInvalidData(format!("missing {key} value at {}", self.time))
+        })
+    }
+}
+
+/// Trait implemented by typed data point of technical indicator
+pub trait IndicatorPoint: Sized {
+    /// Create typed data point from data collector
+    ///
+    /// # Errors
+    /// Raise error if data collector does not contain value required by point
+    fn from_data(data: &DataCollector) -> Result<Self>;
+}
+
+/// Data point of indicator which returns single value such as SMA, EMA or RSI
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ValuePoint {
+    time: String,
+    value: f64,
+}
+
+impl ValuePoint {
+    /// Return time
+    #[must_use]
+    pub fn time(&self) -> &str {
+        &self.time
+    }
+
+    /// Return value
+    #[must_use]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl IndicatorPoint for ValuePoint {
+    fn from_data(data: &DataCollector) -> Result<Self> {
+        let mut values = data.values.values();
+        match (values.next(), values.next()) {
+            (Some(value), None) => {
+                Ok(Self {
+                    time: data.time.clone(),
+                    value: *value,
+                })
+            }
+            _ => {
+                Err(Error::AlphaVantageInvalidData(format!(
+                    "expected single value at {} but found {}",
+                    data.time,
+                    data.values.len()
+                )))
+            }
+        }
+    }
+}
+
+/// Create typed data point struct whose fields are read from given keys
+macro_rules! indicator_point {
+    (
+        $(#[$doc:meta])*
+        $name:ident { $($(#[$field_doc:meta])* $field:ident => $key:literal),+ $(,)? }
+    ) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+        pub struct $name {
+            time: String,
+            $($field: f64,)+
+        }
+
+        impl $name {
+            /// Return time
+            #[must_use]
+            pub fn time(&self) -> &str {
+                &self.time
+            }
+
+            $(
+                $(#[$field_doc])*
+                #[must_use]
+                pub fn $field(&self) -> f64 {
+                    self.$field
+                }
+            )+
+        }
+
+        impl IndicatorPoint for $name {
+            fn from_data(data: &DataCollector) -> Result<Self> {
+                Ok(Self {
+                    time: data.time.clone(),
+                    $($field: data.required_value($key)?,)+
+                })
+            }
+        }
+    };
+}
+
+indicator_point!(
+    /// Data point of MACD and MACDEXT indicator
+    ///
+    /// ```
+    /// use alpha_vantage::technical_indicator::{MacdPoint, TechnicalIndicatorInterval};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let indicator = api
+    ///         .technical_indicator("MACD", "IBM", TechnicalIndicatorInterval::Daily)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     let points = indicator.points::<MacdPoint>().unwrap();
+    ///     let latest = points.last().unwrap();
+    ///     assert_eq!(latest.time(), "2023-09-29");
+    ///     assert_eq!(latest.macd(), -0.2474);
+    ///     assert_eq!(latest.signal(), 0.2538);
+    ///     assert_eq!(latest.hist(), -0.5012);
+    /// }
+    /// ```
+    MacdPoint {
+        /// Return MACD value
+        macd => "MACD",
+        /// Return signal value
+        signal => "MACD_Signal",
+        /// Return histogram value
+        hist => "MACD_Hist",
+    }
+);
+
+indicator_point!(
+    /// Data point of BBANDS indicator
+    BBandsPoint {
+        /// Return upper band value
+        upper => "Real Upper Band",
+        /// Return middle band value
+        middle => "Real Middle Band",
+        /// Return lower band value
+        lower => "Real Lower Band",
+    }
+);
+
+indicator_point!(
+    /// Data point of STOCH indicator
+    StochPoint {
+        /// Return slow K value
+        slow_k => "SlowK",
+        /// Return slow D value
+        slow_d => "SlowD",
+    }
+);
+
+indicator_point!(
+    /// Data point of STOCHF and STOCHRSI indicator
+    StochFastPoint {
+        /// Return fast K value
+        fast_k => "FastK",
+        /// Return fast D value
+        fast_d => "FastD",
+    }
+);
+
+indicator_point!(
+    /// Data point of AROON indicator
+    AroonPoint {
+        /// Return aroon up value
+        up => "Aroon Up",
+        /// Return aroon down value
+        down => "Aroon Down",
+    }
+);
+
+indicator_point!(
+    /// Data point of MAMA indicator
+    ///
+    /// ```
+    /// use alpha_vantage::technical_indicator::{MamaPoint, TechnicalIndicatorInterval};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let indicator = api
+    ///         .technical_indicator("MAMA", "IBM", TechnicalIndicatorInterval::Daily)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     let points = indicator.points::<MamaPoint>().unwrap();
+    ///     assert_eq!(points[0].time(), "2023-09-28");
+    ///     assert_eq!(points[0].mama(), 146.2735);
+    ///     assert_eq!(points[0].fama(), 144.5319);
+    /// }
+    /// ```
+    MamaPoint {
+        /// Return MAMA value
+        mama => "MAMA",
+        /// Return FAMA value
+        fama => "FAMA",
+    }
+);
+
+indicator_point!(
+    /// Data point of `HT_SINE` indicator
+    HtSinePoint {
+        /// Return sine value
+        sine => "SINE",
+        /// Return lead sine value
+        lead_sine => "LEAD SINE",
+    }
+);
+
+indicator_point!(
+    /// Data point of `HT_PHASOR` indicator
+    HtPhasorPoint {
+        /// Return phase value
+        phase => "PHASE",
+        /// Return quadrature value
+        quadrature => "QUADRATURE",
+    }
+);
+
+/// Struct for storing meta data of indicator
+///
+/// Serialized as object with `symbol`, `indicator`, `last_refreshed`,
+/// `interval`, `time_period`, `series_type` and `time_zone`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IndicatorMetaData {
+    symbol: String,
+    indicator: String,
+    last_refreshed: String,
+    interval: String,
+    time_period: Option<u64>,
+    series_type: Option<String>,
+    time_zone: String,
+}
+
+impl IndicatorMetaData {
+    /// Return symbol
+    #[must_use]
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Return name of indicator
+    #[must_use]
+    pub fn indicator(&self) -> &str {
+        &self.indicator
+    }
+
+    /// Return last refreshed time
+    #[must_use]
+    pub fn last_refreshed(&self) -> &str {
+        &self.last_refreshed
+    }
+
+    /// Return interval
+    #[must_use]
+    pub fn interval(&self) -> &str {
+        &self.interval
+    }
+
+    /// Return time period. Returns None if indicator does not use time period
+    #[must_use]
+    pub fn time_period(&self) -> Option<u64> {
+        self.time_period
+    }
+
+    /// Return series type. Returns None if indicator does not use series type
+    #[must_use]
+    pub fn series_type(&self) -> Option<&str> {
+        self.series_type.as_deref()
+    }
+
+    /// Return time zone
+    #[must_use]
+    pub fn time_zone(&self) -> &str {
+        &self.time_zone
+    }
 }
 
 /// Struct for indicator
-#[derive(Default, Debug)]
+///
+/// Serialized as object with `metadata` containing `symbol`, `indicator`,
+/// `last_refreshed`, `interval`, `time_period`, `series_type` and `time_zone`
+/// and `data` containing list of object with `time` and `values` sorted by
+/// time. Meta data other than these fields is not serialized. Serialization
+/// fails if meta data or data is invalid
+///
+/// ```
+/// use alpha_vantage::technical_indicator::{
+///     MacdPoint, TechnicalIndicator, TechnicalIndicatorInterval,
+/// };
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+///     let api = alpha_vantage::set_api("demo", client);
+///     let indicator = api
+///         .technical_indicator("MACD", "IBM", TechnicalIndicatorInterval::Daily)
+///         .json()
+///         .await
+///         .unwrap();
+///     let json = serde_json::to_value(&indicator).unwrap();
+///     assert_eq!(json["metadata"]["symbol"], "IBM");
+///     assert_eq!(json["data"][0]["time"], "2023-09-27");
+///     let restored: TechnicalIndicator = serde_json::from_value(json).unwrap();
+///     assert_eq!(
+///         restored.indicator_meta_data().unwrap().time_zone(),
+///         "US/Eastern"
+///     );
+///     assert_eq!(
+///         restored
+///             .points::<MacdPoint>()
+///             .unwrap()
+///             .last()
+///             .unwrap()
+///             .macd(),
+///         indicator
+///             .points::<MacdPoint>()
+///             .unwrap()
+///             .last()
+///             .unwrap()
+///             .macd()
+///     );
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Deserialize)]
+#[serde(from = "TechnicalIndicatorRecord")]
 pub struct TechnicalIndicator {
     metadata: HashMap<String, Value>,
     data: DataType,
 }
 
+/// Normalized representation of `TechnicalIndicator` used for serializing and
+/// deserializing indicator
+#[derive(Serialize, Deserialize)]
+struct TechnicalIndicatorRecord {
+    metadata: IndicatorMetaData,
+    data: Vec<DataCollector>,
+}
+
+impl From<TechnicalIndicatorRecord> for TechnicalIndicator {
+    fn from(record: TechnicalIndicatorRecord) -> Self {
+        let IndicatorMetaData {
+            symbol,
+            indicator,
+            last_refreshed,
+            interval,
+            time_period,
+            series_type,
+            time_zone,
+        } = record.metadata;
+        let mut metadata = HashMap::from([
+            ("Symbol".to_string(), Value::from(symbol)),
+            ("Indicator".to_string(), Value::from(indicator)),
+            ("Last Refreshed".to_string(), Value::from(last_refreshed)),
+            ("Interval".to_string(), Value::from(interval)),
+            ("Time Zone".to_string(), Value::from(time_zone)),
+        ]);
+        if let Some(time_period) = time_period {
+            metadata.insert("Time Period".to_string(), Value::from(time_period));
+        }
+        if let Some(series_type) = series_type {
+            metadata.insert("Series Type".to_string(), Value::from(series_type));
+        }
+        let series = record
+            .data
+            .into_iter()
+            .map(|data| {
+                let values = data
+                    .values
+                    .into_iter()
+                    .map(|(key, value)| (key, value.to_string()))
+                    .collect();
+                (data.time, values)
+            })
+            .collect();
+        let data = HashMap::from([("Technical Analysis".to_string(), series)]);
+        Self { metadata, data }
+    }
+}
+
+impl Serialize for TechnicalIndicator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut data = self.data().map_err(serde::ser::Error::custom)?;
+        data.sort_by(|first, second| first.time.cmp(&second.time));
+        TechnicalIndicatorRecord {
+            metadata: self
+                .indicator_meta_data()
+                .map_err(serde::ser::Error::custom)?,
+            data,
+        }
+        .serialize(serializer)
+    }
+}
+
 impl TechnicalIndicator {
     /// Return meta data in hash form with key as `String` and values as
     /// `serde_json::value::Value`
@@ -57,30 +459,139 @@ impl TechnicalIndicator {
 
     /// Return data as a vector
     ///
+    /// ```
+    /// use alpha_vantage::error::Error;
+    /// use alpha_vantage::mock::MockClient;
+    /// use alpha_vantage::technical_indicator::TechnicalIndicatorInterval;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = MockClient::new().response(
+    ///         "function=SMA",
+    ///         r#"{"Meta Data": {"1: Symbol": "IBM"},
+    ///             "Technical Analysis: SMA": {"2024-01-02": {"SMA": "abc"}}}"#,
+    ///     );
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let indicator = api
+    ///         .technical_indicator("SMA", "IBM", TechnicalIndicatorInterval::Daily)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     assert!(matches!(
+    ///         indicator.data(),
+    ///         Err(Error::AlphaVantageInvalidData(_))
+    ///     ));
+    /// }
+    /// ```
+    ///
     /// # Errors
     /// When alpha vantage contains data in other format
     pub fn data(&self) -> Result<Vec<DataCollector>> {
         let mut vector = Vec::new();
         for hash in self.data.values() {
-            for time in hash.keys() {
+            for (time, hash_values) in hash {
                 let mut data_collector = DataCollector {
-                    time: time.to_string(),
+                    time: time.clone(),
                     ..DataCollector::default()
                 };
-                let hash_values = hash.get(time).ok_or(Error::AlphaVantageInvalidData)?;
 
                 for (key, value) in hash_values {
-                    let value_f64 = value
-                        .trim()
-                        .parse::<f64>()
-                        .map_err(|_| Error::AlphaVantageInvalidData)?;
-                    data_collector.values.insert(key.to_string(), value_f64);
+                    let value_f64 = value.trim().parse::<f64>().map_err(|_| {
+                        Error::AlphaVantageInvalidData(format!(
+                            "invalid value {value} for {key} at {time}"
+                        ))
+                    })?;
+                    data_collector.values.insert(key.clone(), value_f64);
                 }
                 vector.push(data_collector);
             }
         }
         Ok(vector)
     }
+
+    /// Return data as vector of typed point sorted by time
+    ///
+    /// # Errors
+    /// When alpha vantage contains data in other format or data does not
+    /// contain value required by point
+    pub fn points<T>(&self) -> Result<Vec<T>>
+    where
+        T: IndicatorPoint,
+    {
+        let mut data = self.data()?;
+        data.sort_by(|a, b| a.time.cmp(&b.time));
+        data.iter().map(T::from_data).collect()
+    }
+
+    /// Return typed meta data
+    ///
+    /// ```
+    /// use alpha_vantage::technical_indicator::TechnicalIndicatorInterval;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let indicator = api
+    ///         .technical_indicator("MACD", "IBM", TechnicalIndicatorInterval::Daily)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     let meta_data = indicator.indicator_meta_data().unwrap();
+    ///     assert_eq!(meta_data.symbol(), "IBM");
+    ///     assert_eq!(meta_data.interval(), "daily");
+    ///     assert_eq!(meta_data.time_period(), None);
+    ///     assert_eq!(meta_data.series_type(), Some("close"));
+    ///     assert_eq!(meta_data.time_zone(), "US/Eastern");
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if meta data does not contain symbol, indicator, last
+    /// refreshed, interval or time zone or if time period is not a number
+    pub fn indicator_meta_data(&self) -> Result<IndicatorMetaData> {
+        let time_period = self
+            .meta_value("Time Period")
+            .map(|time_period| {
+                time_period.parse().map_err(|_| {
+                    Error::AlphaVantageInvalidData(format!("invalid time period {time_period}"))
+                })
+            })
+            .transpose()?;
+        Ok(IndicatorMetaData {
+            symbol: self.required_meta_value("Symbol")?,
+            indicator: self.required_meta_value("Indicator")?,
+            last_refreshed: self.required_meta_value("Last Refreshed")?,
+            interval: self.required_meta_value("Interval")?,
+            time_period,
+            series_type: self.meta_value("Series Type"),
+            time_zone: self.required_meta_value("Time Zone")?,
+        })
+    }
+
+    /// Return meta data value whose key label matches name. Key of meta data
+    /// is prefixed with position such as `1: Symbol` or `5.1: Fast Period`
+    fn meta_value(&self, name: &str) -> Option<String> {
+        self.metadata.iter().find_map(|(key, value)| {
+            let label = key
+                .split_once(": ")
+                .map_or(key.as_str(), |(_, label)| label);
+            if !label.eq_ignore_ascii_case(name) {
+                return None;
+            }
+            match value {
+                Value::String(value) => Some(value.clone()),
+                value => Some(value.to_string()),
+            }
+        })
+    }
+
+    /// Return meta data value or error if value is not present
+    fn required_meta_value(&self, name: &str) -> Result<String> {
+        self.meta_value(name).ok_or_else(|| {
+            Error::AlphaVantageInvalidData(format!("meta data does not contain {name}"))
+        })
+    }
 }
 
 /// Struct for helping `TechnicalIndicator` struct
@@ -101,13 +612,10 @@ pub(crate) struct TechnicalIndicatorHelper {
 impl TechnicalIndicatorHelper {
     fn convert(self) -> Result<TechnicalIndicator> {
         detect_common_helper_error(self.information, self.error_message, self.note)?;
-        if self.metadata.is_none() || self.data.is_none() {
+        let (Some(metadata), Some(data)) = (self.metadata, self.data) else {
             return Err(Error::EmptyResponse);
-        }
-        Ok(TechnicalIndicator {
-            metadata: self.metadata.unwrap(),
-            data: self.data.unwrap(),
-        })
+        };
+        Ok(TechnicalIndicator { metadata, data })
     }
 }
 
@@ -120,10 +628,11 @@ pub struct TechnicalIndicatorBuilder<'a> {
     time_period: Option<u64>,
     series_type: Option<&'a str>,
     extra_params: HashMap<String, String>,
+    typed_function: Option<TechnicalIndicatorFunction>,
 }
 
 impl<'a> TechnicalIndicatorBuilder<'a> {
-    crate::json_data_struct!(TechnicalIndicator, TechnicalIndicatorHelper);
+    crate::json_data_struct!(TechnicalIndicator, TechnicalIndicatorHelper, validate);
 
     /// Create new `TechnicalIndicatorBuilder` form `APIClient`
     #[must_use]
@@ -141,22 +650,40 @@ impl<'a> TechnicalIndicatorBuilder<'a> {
             time_period: None,
             series_type: None,
             extra_params: HashMap::new(),
+            typed_function: None,
         }
     }
 
-    /// Set time period for API
+    /// Create new `TechnicalIndicatorBuilder` from `APIClient` using typed
+    /// function. Parameter of function is validated before request is sent
+    #[must_use]
+    pub fn with_function(
+        api_client: &'a ApiClient,
+        function: TechnicalIndicatorFunction,
+        symbol: &'a str,
+        interval: TechnicalIndicatorInterval,
+    ) -> Self {
+        let mut builder = Self::new(api_client, function.function(), symbol, interval);
+        builder.typed_function = Some(function);
+        builder
+    }
+
+    /// Set time period for API. Cannot be used with builder created from typed
+    /// function
     pub fn time_period(&mut self, time: u64) -> &mut Self {
         self.time_period = Some(time);
         self
     }
 
-    /// Set series type for API
+    /// Set series type for API. Cannot be used with builder created from typed
+    /// function
     pub fn series_type(&mut self, series_type: &'a str) -> &mut Self {
         self.series_type = Some(series_type);
         self
     }
 
-    /// Add extra param to builder
+    /// Add extra param to builder. Cannot be used with builder created from
+    /// typed function
     pub fn extra_param<T, U>(&mut self, param: T, value: U) -> &mut Self
     where
         T: std::fmt::Display,
@@ -167,6 +694,49 @@ impl<'a> TechnicalIndicatorBuilder<'a> {
         self
     }
 
+    /// Validate typed function. Raw parameter cannot be set along with typed
+    /// function as it may override or duplicate typed parameter
+    ///
+    /// ```
+    /// use alpha_vantage::error::Error;
+    /// use alpha_vantage::technical_indicator::{
+    ///     PeriodSeriesParams, SeriesType, TechnicalIndicatorFunction, TechnicalIndicatorInterval,
+    /// };
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::new();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let error = api
+    ///         .indicator(
+    ///             TechnicalIndicatorFunction::Sma(PeriodSeriesParams::new(10, SeriesType::Close)),
+    ///             "IBM",
+    ///             TechnicalIndicatorInterval::Daily,
+    ///         )
+    ///         .time_period(20)
+    ///         .json()
+    ///         .await
+    ///         .err()
+    ///         .unwrap();
+    ///     assert!(matches!(error, Error::InvalidParameter(_)));
+    /// }
+    /// ```
+    fn validate(&self) -> Result<()> {
+        if let Some(typed_function) = &self.typed_function {
+            if self.time_period.is_some()
+                || self.series_type.is_some()
+                || !self.extra_params.is_empty()
+            {
+                return Err(Error::InvalidParameter(
+                    "time_period, series_type and extra_param cannot be used with typed function"
+                        .to_string(),
+                ));
+            }
+            typed_function.validate(&self.interval)?;
+        }
+        Ok(())
+    }
+
     fn create_url(&self) -> String {
         let interval_val = match self.interval {
             TechnicalIndicatorInterval::OneMin => "1min",
@@ -185,15 +755,21 @@ impl<'a> TechnicalIndicatorBuilder<'a> {
         );
 
         if let Some(time_period) = &self.time_period {
-            created_link.push_str(&format!("&time_period={time_period}"));
+            let _ = write!(created_link, "&time_period={time_period}");
         }
 
         if let Some(series_type) = &self.series_type {
-            created_link.push_str(&format!("&series_type={series_type}"));
+            let _ = write!(created_link, "&series_type={series_type}");
+        }
+
+        if let Some(typed_function) = &self.typed_function {
+            for (param, value) in typed_function.params() {
+                let _ = write!(created_link, "&{param}={value}");
+            }
         }
 
         for (param, value) in &self.extra_params {
-            created_link.push_str(&format!("&{param}={value}"));
+            let _ = write!(created_link, "&{param}={value}");
         }
 
         created_link
@@ -220,3 +796,1242 @@ pub enum TechnicalIndicatorInterval {
     /// monthly interval
     Monthly,
 }
+
+impl TechnicalIndicatorInterval {
+    /// Return true if interval is intraday interval
+    #[must_use]
+    pub fn is_intraday(&self) -> bool {
+        matches!(
+            self,
+            TechnicalIndicatorInterval::OneMin
+                | TechnicalIndicatorInterval::FiveMin
+                | TechnicalIndicatorInterval::FifteenMin
+                | TechnicalIndicatorInterval::ThirtyMin
+                | TechnicalIndicatorInterval::SixtyMin
+        )
+    }
+}
+
+/// Enum for declaring price series used for computing indicator
+#[derive(Clone, Copy)]
+pub enum SeriesType {
+    /// close price
+    Close,
+    /// open price
+    Open,
+    /// high price
+    High,
+    /// low price
+    Low,
+}
+
+impl SeriesType {
+    fn as_str(self) -> &'static str {
+        match self {
+            SeriesType::Close => "close",
+            SeriesType::Open => "open",
+            SeriesType::High => "high",
+            SeriesType::Low => "low",
+        }
+    }
+}
+
+/// Enum for declaring moving average type used by indicator
+#[derive(Clone, Copy)]
+pub enum MovingAverageType {
+    /// Simple moving average
+    Sma,
+    /// Exponential moving average
+    Ema,
+    /// Weighted moving average
+    Wma,
+    /// Double exponential moving average
+    Dema,
+    /// Triple exponential moving average
+    Tema,
+    /// Triangular moving average
+    Trima,
+    /// T3 moving average
+    T3,
+    /// Kaufman adaptive moving average
+    Kama,
+    /// MESA adaptive moving average
+    Mama,
+}
+
+impl MovingAverageType {
+    fn value(self) -> u8 {
+        match self {
+            MovingAverageType::Sma => 0,
+            MovingAverageType::Ema => 1,
+            MovingAverageType::Wma => 2,
+            MovingAverageType::Dema => 3,
+            MovingAverageType::Tema => 4,
+            MovingAverageType::Trima => 5,
+            MovingAverageType::T3 => 6,
+            MovingAverageType::Kama => 7,
+            MovingAverageType::Mama => 8,
+        }
+    }
+}
+
+/// Query parameter of typed indicator
+type Params = Vec<(&'static str, String)>;
+
+/// Return error if period is zero
+fn check_period(name: &str, period: u64) -> Result<()> {
+    if period == 0 {
+        return Err(Error::InvalidParameter(format!(
+            "{name} must be greater than zero"
+        )));
+    }
+    Ok(())
+}
+
+/// Return error if optional period is zero
+fn check_optional_period(name: &str, period: Option<u64>) -> Result<()> {
+    period.map_or(Ok(()), |period| check_period(name, period))
+}
+
+/// Return error if fast period is not less than slow period. Period which is
+/// not set is compared using default value used by API
+fn check_fast_slow(fast: Option<u64>, slow: Option<u64>, default: (u64, u64)) -> Result<()> {
+    check_optional_period("fastperiod", fast)?;
+    check_optional_period("slowperiod", slow)?;
+    if (fast.is_some() || slow.is_some()) && fast.unwrap_or(default.0) >= slow.unwrap_or(default.1)
+    {
+        return Err(Error::InvalidParameter(
+            "fastperiod must be less than slowperiod".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Return error if value is not finite positive number
+fn check_positive(name: &str, value: Option<f64>) -> Result<()> {
+    match value {
+        Some(value) if !value.is_finite() || value <= 0.0 => {
+            Err(Error::InvalidParameter(format!(
+                "{name} must be a positive number"
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Push optional parameter to params
+fn push_optional<T>(params: &mut Params, name: &'static str, value: Option<T>)
+where
+    T: ToString,
+{
+    if let Some(value) = value {
+        params.push((name, value.to_string()));
+    }
+}
+
+/// Parameter for indicator which requires time period and series type
+#[derive(Clone)]
+pub struct PeriodSeriesParams {
+    time_period: u64,
+    series_type: SeriesType,
+}
+
+impl PeriodSeriesParams {
+    /// Create new parameter with time period and series type
+    #[must_use]
+    pub fn new(time_period: u64, series_type: SeriesType) -> Self {
+        Self {
+            time_period,
+            series_type,
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_period("time_period", self.time_period)
+    }
+
+    fn params(&self) -> Params {
+        vec![
+            ("time_period", self.time_period.to_string()),
+            ("series_type", self.series_type.as_str().to_string()),
+        ]
+    }
+}
+
+/// Parameter for indicator which requires only time period
+#[derive(Clone)]
+pub struct PeriodParams {
+    time_period: u64,
+}
+
+impl PeriodParams {
+    /// Create new parameter with time period
+    #[must_use]
+    pub fn new(time_period: u64) -> Self {
+        Self { time_period }
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_period("time_period", self.time_period)
+    }
+
+    fn params(&self) -> Params {
+        vec![("time_period", self.time_period.to_string())]
+    }
+}
+
+/// Parameter for indicator which requires only series type
+#[derive(Clone)]
+pub struct SeriesParams {
+    series_type: SeriesType,
+}
+
+impl SeriesParams {
+    /// Create new parameter with series type
+    #[must_use]
+    pub fn new(series_type: SeriesType) -> Self {
+        Self { series_type }
+    }
+
+    fn params(&self) -> Params {
+        vec![("series_type", self.series_type.as_str().to_string())]
+    }
+}
+
+/// Parameter for MAMA indicator
+#[derive(Clone)]
+pub struct MamaParams {
+    series_type: SeriesType,
+    fast_limit: Option<f64>,
+    slow_limit: Option<f64>,
+}
+
+impl MamaParams {
+    /// Create new parameter with series type
+    #[must_use]
+    pub fn new(series_type: SeriesType) -> Self {
+        Self {
+            series_type,
+            fast_limit: None,
+            slow_limit: None,
+        }
+    }
+
+    /// Set fast limit. Value should be between 0 and 1
+    #[must_use]
+    pub fn fast_limit(mut self, fast_limit: f64) -> Self {
+        self.fast_limit = Some(fast_limit);
+        self
+    }
+
+    /// Set slow limit. Value should be between 0 and 1
+    #[must_use]
+    pub fn slow_limit(mut self, slow_limit: f64) -> Self {
+        self.slow_limit = Some(slow_limit);
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        for (name, limit) in [
+            ("fastlimit", self.fast_limit),
+            ("slowlimit", self.slow_limit),
+        ] {
+            check_positive(name, limit)?;
+            if limit.is_some_and(|limit| limit >= 1.0) {
+                return Err(Error::InvalidParameter(format!(
+                    "{name} must be less than 1"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn params(&self) -> Params {
+        let mut params = vec![("series_type", self.series_type.as_str().to_string())];
+        push_optional(&mut params, "fastlimit", self.fast_limit);
+        push_optional(&mut params, "slowlimit", self.slow_limit);
+        params
+    }
+}
+
+/// Parameter for MACD indicator. Fast period needs to be less than slow
+/// period, period which is not set is compared using API default of 12 for
+/// fast period and 26 for slow period
+///
+/// ```
+/// use alpha_vantage::error::Error;
+/// use alpha_vantage::technical_indicator::{
+///     MacdParams, SeriesType, TechnicalIndicatorFunction, TechnicalIndicatorInterval,
+/// };
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = alpha_vantage::mock::MockClient::new();
+///     let api = alpha_vantage::set_api("demo", client);
+///     let error = api
+///         .indicator(
+///             TechnicalIndicatorFunction::Macd(
+///                 MacdParams::new(SeriesType::Close).fast_period(30),
+///             ),
+///             "IBM",
+///             TechnicalIndicatorInterval::Daily,
+///         )
+///         .json()
+///         .await
+///         .err()
+///         .unwrap();
+///     assert!(matches!(error, Error::InvalidParameter(_)));
+/// }
+/// ```
+#[derive(Clone)]
+pub struct MacdParams {
+    series_type: SeriesType,
+    fast_period: Option<u64>,
+    slow_period: Option<u64>,
+    signal_period: Option<u64>,
+}
+
+impl MacdParams {
+    /// Create new parameter with series type
+    #[must_use]
+    pub fn new(series_type: SeriesType) -> Self {
+        Self {
+            series_type,
+            fast_period: None,
+            slow_period: None,
+            signal_period: None,
+        }
+    }
+
+    /// Set fast period
+    #[must_use]
+    pub fn fast_period(mut self, fast_period: u64) -> Self {
+        self.fast_period = Some(fast_period);
+        self
+    }
+
+    /// Set slow period
+    #[must_use]
+    pub fn slow_period(mut self, slow_period: u64) -> Self {
+        self.slow_period = Some(slow_period);
+        self
+    }
+
+    /// Set signal period
+    #[must_use]
+    pub fn signal_period(mut self, signal_period: u64) -> Self {
+        self.signal_period = Some(signal_period);
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_fast_slow(self.fast_period, self.slow_period, (12, 26))?;
+        check_optional_period("signalperiod", self.signal_period)
+    }
+
+    fn params(&self) -> Params {
+        let mut params = vec![("series_type", self.series_type.as_str().to_string())];
+        push_optional(&mut params, "fastperiod", self.fast_period);
+        push_optional(&mut params, "slowperiod", self.slow_period);
+        push_optional(&mut params, "signalperiod", self.signal_period);
+        params
+    }
+}
+
+/// Parameter for MACDEXT indicator
+#[derive(Clone)]
+pub struct MacdExtParams {
+    macd: MacdParams,
+    fast_ma_type: Option<MovingAverageType>,
+    slow_ma_type: Option<MovingAverageType>,
+    signal_ma_type: Option<MovingAverageType>,
+}
+
+impl MacdExtParams {
+    /// Create new parameter with series type
+    #[must_use]
+    pub fn new(series_type: SeriesType) -> Self {
+        Self {
+            macd: MacdParams::new(series_type),
+            fast_ma_type: None,
+            slow_ma_type: None,
+            signal_ma_type: None,
+        }
+    }
+
+    /// Set fast period
+    #[must_use]
+    pub fn fast_period(mut self, fast_period: u64) -> Self {
+        self.macd = self.macd.fast_period(fast_period);
+        self
+    }
+
+    /// Set slow period
+    #[must_use]
+    pub fn slow_period(mut self, slow_period: u64) -> Self {
+        self.macd = self.macd.slow_period(slow_period);
+        self
+    }
+
+    /// Set signal period
+    #[must_use]
+    pub fn signal_period(mut self, signal_period: u64) -> Self {
+        self.macd = self.macd.signal_period(signal_period);
+        self
+    }
+
+    /// Set moving average type of fast period
+    #[must_use]
+    pub fn fast_ma_type(mut self, ma_type: MovingAverageType) -> Self {
+        self.fast_ma_type = Some(ma_type);
+        self
+    }
+
+    /// Set moving average type of slow period
+    #[must_use]
+    pub fn slow_ma_type(mut self, ma_type: MovingAverageType) -> Self {
+        self.slow_ma_type = Some(ma_type);
+        self
+    }
+
+    /// Set moving average type of signal period
+    #[must_use]
+    pub fn signal_ma_type(mut self, ma_type: MovingAverageType) -> Self {
+        self.signal_ma_type = Some(ma_type);
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        self.macd.validate()
+    }
+
+    fn params(&self) -> Params {
+        let mut params = self.macd.params();
+        push_optional(
+            &mut params,
+            "fastmatype",
+            self.fast_ma_type.map(MovingAverageType::value),
+        );
+        push_optional(
+            &mut params,
+            "slowmatype",
+            self.slow_ma_type.map(MovingAverageType::value),
+        );
+        push_optional(
+            &mut params,
+            "signalmatype",
+            self.signal_ma_type.map(MovingAverageType::value),
+        );
+        params
+    }
+}
+
+/// Parameter for STOCH indicator
+#[derive(Clone, Default)]
+pub struct StochParams {
+    fastk_period: Option<u64>,
+    slowk_period: Option<u64>,
+    slowd_period: Option<u64>,
+    slowk_ma_type: Option<MovingAverageType>,
+    slowd_ma_type: Option<MovingAverageType>,
+}
+
+impl StochParams {
+    /// Create new parameter which uses default value for all parameter
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set fast k period
+    #[must_use]
+    pub fn fastk_period(mut self, period: u64) -> Self {
+        self.fastk_period = Some(period);
+        self
+    }
+
+    /// Set slow k period
+    #[must_use]
+    pub fn slowk_period(mut self, period: u64) -> Self {
+        self.slowk_period = Some(period);
+        self
+    }
+
+    /// Set slow d period
+    #[must_use]
+    pub fn slowd_period(mut self, period: u64) -> Self {
+        self.slowd_period = Some(period);
+        self
+    }
+
+    /// Set moving average type of slow k
+    #[must_use]
+    pub fn slowk_ma_type(mut self, ma_type: MovingAverageType) -> Self {
+        self.slowk_ma_type = Some(ma_type);
+        self
+    }
+
+    /// Set moving average type of slow d
+    #[must_use]
+    pub fn slowd_ma_type(mut self, ma_type: MovingAverageType) -> Self {
+        self.slowd_ma_type = Some(ma_type);
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_optional_period("fastkperiod", self.fastk_period)?;
+        check_optional_period("slowkperiod", self.slowk_period)?;
+        check_optional_period("slowdperiod", self.slowd_period)
+    }
+
+    fn params(&self) -> Params {
+        let mut params = Vec::new();
+        push_optional(&mut params, "fastkperiod", self.fastk_period);
+        push_optional(&mut params, "slowkperiod", self.slowk_period);
+        push_optional(&mut params, "slowdperiod", self.slowd_period);
+        push_optional(
+            &mut params,
+            "slowkmatype",
+            self.slowk_ma_type.map(MovingAverageType::value),
+        );
+        push_optional(
+            &mut params,
+            "slowdmatype",
+            self.slowd_ma_type.map(MovingAverageType::value),
+        );
+        params
+    }
+}
+
+/// Parameter for STOCHF indicator
+#[derive(Clone, Default)]
+pub struct StochFastParams {
+    fastk_period: Option<u64>,
+    fastd_period: Option<u64>,
+    fastd_ma_type: Option<MovingAverageType>,
+}
+
+impl StochFastParams {
+    /// Create new parameter which uses default value for all parameter
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set fast k period
+    #[must_use]
+    pub fn fastk_period(mut self, period: u64) -> Self {
+        self.fastk_period = Some(period);
+        self
+    }
+
+    /// Set fast d period
+    #[must_use]
+    pub fn fastd_period(mut self, period: u64) -> Self {
+        self.fastd_period = Some(period);
+        self
+    }
+
+    /// Set moving average type of fast d
+    #[must_use]
+    pub fn fastd_ma_type(mut self, ma_type: MovingAverageType) -> Self {
+        self.fastd_ma_type = Some(ma_type);
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_optional_period("fastkperiod", self.fastk_period)?;
+        check_optional_period("fastdperiod", self.fastd_period)
+    }
+
+    fn params(&self) -> Params {
+        let mut params = Vec::new();
+        push_optional(&mut params, "fastkperiod", self.fastk_period);
+        push_optional(&mut params, "fastdperiod", self.fastd_period);
+        push_optional(
+            &mut params,
+            "fastdmatype",
+            self.fastd_ma_type.map(MovingAverageType::value),
+        );
+        params
+    }
+}
+
+/// Parameter for STOCHRSI indicator
+#[derive(Clone)]
+pub struct StochRsiParams {
+    period_series: PeriodSeriesParams,
+    stoch: StochFastParams,
+}
+
+impl StochRsiParams {
+    /// Create new parameter with time period and series type
+    #[must_use]
+    pub fn new(time_period: u64, series_type: SeriesType) -> Self {
+        Self {
+            period_series: PeriodSeriesParams::new(time_period, series_type),
+            stoch: StochFastParams::new(),
+        }
+    }
+
+    /// Set fast k period
+    #[must_use]
+    pub fn fastk_period(mut self, period: u64) -> Self {
+        self.stoch = self.stoch.fastk_period(period);
+        self
+    }
+
+    /// Set fast d period
+    #[must_use]
+    pub fn fastd_period(mut self, period: u64) -> Self {
+        self.stoch = self.stoch.fastd_period(period);
+        self
+    }
+
+    /// Set moving average type of fast d
+    #[must_use]
+    pub fn fastd_ma_type(mut self, ma_type: MovingAverageType) -> Self {
+        self.stoch = self.stoch.fastd_ma_type(ma_type);
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        self.period_series.validate()?;
+        self.stoch.validate()
+    }
+
+    fn params(&self) -> Params {
+        let mut params = self.period_series.params();
+        params.extend(self.stoch.params());
+        params
+    }
+}
+
+/// Parameter for APO and PPO indicator. Fast period needs to be less than
+/// slow period, period which is not set is compared using API default of 12
+/// for fast period and 26 for slow period
+#[derive(Clone)]
+pub struct PriceOscillatorParams {
+    series_type: SeriesType,
+    fast_period: Option<u64>,
+    slow_period: Option<u64>,
+    ma_type: Option<MovingAverageType>,
+}
+
+impl PriceOscillatorParams {
+    /// Create new parameter with series type
+    #[must_use]
+    pub fn new(series_type: SeriesType) -> Self {
+        Self {
+            series_type,
+            fast_period: None,
+            slow_period: None,
+            ma_type: None,
+        }
+    }
+
+    /// Set fast period
+    #[must_use]
+    pub fn fast_period(mut self, fast_period: u64) -> Self {
+        self.fast_period = Some(fast_period);
+        self
+    }
+
+    /// Set slow period
+    #[must_use]
+    pub fn slow_period(mut self, slow_period: u64) -> Self {
+        self.slow_period = Some(slow_period);
+        self
+    }
+
+    /// Set moving average type
+    #[must_use]
+    pub fn ma_type(mut self, ma_type: MovingAverageType) -> Self {
+        self.ma_type = Some(ma_type);
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_fast_slow(self.fast_period, self.slow_period, (12, 26))
+    }
+
+    fn params(&self) -> Params {
+        let mut params = vec![("series_type", self.series_type.as_str().to_string())];
+        push_optional(&mut params, "fastperiod", self.fast_period);
+        push_optional(&mut params, "slowperiod", self.slow_period);
+        push_optional(
+            &mut params,
+            "matype",
+            self.ma_type.map(MovingAverageType::value),
+        );
+        params
+    }
+}
+
+/// Parameter for ULTOSC indicator
+#[derive(Clone, Default)]
+pub struct UltimateOscillatorParams {
+    time_periods: [Option<u64>; 3],
+}
+
+impl UltimateOscillatorParams {
+    /// Create new parameter which uses default value for all parameter
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set first time period
+    #[must_use]
+    pub fn time_period1(mut self, period: u64) -> Self {
+        self.time_periods[0] = Some(period);
+        self
+    }
+
+    /// Set second time period
+    #[must_use]
+    pub fn time_period2(mut self, period: u64) -> Self {
+        self.time_periods[1] = Some(period);
+        self
+    }
+
+    /// Set third time period
+    #[must_use]
+    pub fn time_period3(mut self, period: u64) -> Self {
+        self.time_periods[2] = Some(period);
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        for (name, period) in ["timeperiod1", "timeperiod2", "timeperiod3"]
+            .into_iter()
+            .zip(self.time_periods)
+        {
+            check_optional_period(name, period)?;
+        }
+        Ok(())
+    }
+
+    fn params(&self) -> Params {
+        let mut params = Vec::new();
+        for (name, period) in ["timeperiod1", "timeperiod2", "timeperiod3"]
+            .into_iter()
+            .zip(self.time_periods)
+        {
+            push_optional(&mut params, name, period);
+        }
+        params
+    }
+}
+
+/// Parameter for BBANDS indicator
+#[derive(Clone)]
+pub struct BBandsParams {
+    period_series: PeriodSeriesParams,
+    nbdevup: Option<f64>,
+    nbdevdn: Option<f64>,
+    ma_type: Option<MovingAverageType>,
+}
+
+impl BBandsParams {
+    /// Create new parameter with time period and series type
+    #[must_use]
+    pub fn new(time_period: u64, series_type: SeriesType) -> Self {
+        Self {
+            period_series: PeriodSeriesParams::new(time_period, series_type),
+            nbdevup: None,
+            nbdevdn: None,
+            ma_type: None,
+        }
+    }
+
+    /// Set standard deviation multiplier of upper band
+    #[must_use]
+    pub fn nbdevup(mut self, deviation: f64) -> Self {
+        self.nbdevup = Some(deviation);
+        self
+    }
+
+    /// Set standard deviation multiplier of lower band
+    #[must_use]
+    pub fn nbdevdn(mut self, deviation: f64) -> Self {
+        self.nbdevdn = Some(deviation);
+        self
+    }
+
+    /// Set moving average type
+    #[must_use]
+    pub fn ma_type(mut self, ma_type: MovingAverageType) -> Self {
+        self.ma_type = Some(ma_type);
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        self.period_series.validate()?;
+        check_positive("nbdevup", self.nbdevup)?;
+        check_positive("nbdevdn", self.nbdevdn)
+    }
+
+    fn params(&self) -> Params {
+        let mut params = self.period_series.params();
+        push_optional(&mut params, "nbdevup", self.nbdevup);
+        push_optional(&mut params, "nbdevdn", self.nbdevdn);
+        push_optional(
+            &mut params,
+            "matype",
+            self.ma_type.map(MovingAverageType::value),
+        );
+        params
+    }
+}
+
+/// Parameter for SAR indicator
+#[derive(Clone, Default)]
+pub struct SarParams {
+    acceleration: Option<f64>,
+    maximum: Option<f64>,
+}
+
+impl SarParams {
+    /// Create new parameter which uses default value for all parameter
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set acceleration factor
+    #[must_use]
+    pub fn acceleration(mut self, acceleration: f64) -> Self {
+        self.acceleration = Some(acceleration);
+        self
+    }
+
+    /// Set acceleration factor maximum value
+    #[must_use]
+    pub fn maximum(mut self, maximum: f64) -> Self {
+        self.maximum = Some(maximum);
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_positive("acceleration", self.acceleration)?;
+        check_positive("maximum", self.maximum)
+    }
+
+    fn params(&self) -> Params {
+        let mut params = Vec::new();
+        push_optional(&mut params, "acceleration", self.acceleration);
+        push_optional(&mut params, "maximum", self.maximum);
+        params
+    }
+}
+
+/// Parameter for ADOSC indicator. Fast period needs to be less than slow
+/// period, period which is not set is compared using API default of 3 for
+/// fast period and 10 for slow period
+#[derive(Clone, Default)]
+pub struct AdOscParams {
+    fast_period: Option<u64>,
+    slow_period: Option<u64>,
+}
+
+impl AdOscParams {
+    /// Create new parameter which uses default value for all parameter
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set fast period
+    #[must_use]
+    pub fn fast_period(mut self, fast_period: u64) -> Self {
+        self.fast_period = Some(fast_period);
+        self
+    }
+
+    /// Set slow period
+    #[must_use]
+    pub fn slow_period(mut self, slow_period: u64) -> Self {
+        self.slow_period = Some(slow_period);
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_fast_slow(self.fast_period, self.slow_period, (3, 10))
+    }
+
+    fn params(&self) -> Params {
+        let mut params = Vec::new();
+        push_optional(&mut params, "fastperiod", self.fast_period);
+        push_optional(&mut params, "slowperiod", self.slow_period);
+        params
+    }
+}
+
+/// Enum for declaring technical indicator function along with its typed
+/// parameter
+///
+/// ```
+/// use alpha_vantage::technical_indicator::{
+///     MamaParams, SeriesType, TechnicalIndicatorFunction, TechnicalIndicatorInterval,
+/// };
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+///     let api = alpha_vantage::set_api("demo", client);
+///     let indicator = api
+///         .indicator(
+///             TechnicalIndicatorFunction::Mama(
+///                 MamaParams::new(SeriesType::Close)
+///                     .fast_limit(0.02)
+///                     .slow_limit(0.01),
+///             ),
+///             "IBM",
+///             TechnicalIndicatorInterval::Daily,
+///         )
+///         .json()
+///         .await
+///         .unwrap();
+///     assert!(!indicator.data().unwrap().is_empty());
+/// }
+/// ```
+#[derive(Clone)]
+pub enum TechnicalIndicatorFunction {
+    /// Simple moving average (SMA)
+    Sma(PeriodSeriesParams),
+    /// Exponential moving average (EMA)
+    Ema(PeriodSeriesParams),
+    /// Weighted moving average (WMA)
+    Wma(PeriodSeriesParams),
+    /// Double exponential moving average (DEMA)
+    Dema(PeriodSeriesParams),
+    /// Triple exponential moving average (TEMA)
+    Tema(PeriodSeriesParams),
+    /// Triangular moving average (TRIMA)
+    Trima(PeriodSeriesParams),
+    /// Kaufman adaptive moving average (KAMA)
+    Kama(PeriodSeriesParams),
+    /// MESA adaptive moving average (MAMA)
+    Mama(MamaParams),
+    /// Volume weighted average price (VWAP). Only intraday interval is
+    /// supported
+    Vwap,
+    /// Triple exponential moving average (T3)
+    T3(PeriodSeriesParams),
+    /// Moving average convergence / divergence (MACD)
+    Macd(MacdParams),
+    /// Moving average convergence / divergence with controllable moving average
+    /// type (MACDEXT)
+    MacdExt(MacdExtParams),
+    /// Stochastic oscillator (STOCH)
+    Stoch(StochParams),
+    /// Stochastic fast (STOCHF)
+    StochF(StochFastParams),
+    /// Relative strength index (RSI)
+    Rsi(PeriodSeriesParams),
+    /// Stochastic relative strength index (STOCHRSI)
+    StochRsi(StochRsiParams),
+    /// Williams %R (WILLR)
+    Willr(PeriodParams),
+    /// Average directional movement index (ADX)
+    Adx(PeriodParams),
+    /// Average directional movement index rating (ADXR)
+    Adxr(PeriodParams),
+    /// Absolute price oscillator (APO)
+    Apo(PriceOscillatorParams),
+    /// Percentage price oscillator (PPO)
+    Ppo(PriceOscillatorParams),
+    /// Momentum (MOM)
+    Mom(PeriodSeriesParams),
+    /// Balance of power (BOP)
+    Bop,
+    /// Commodity channel index (CCI)
+    Cci(PeriodParams),
+    /// Chande momentum oscillator (CMO)
+    Cmo(PeriodSeriesParams),
+    /// Rate of change (ROC)
+    Roc(PeriodSeriesParams),
+    /// Rate of change ratio (ROCR)
+    Rocr(PeriodSeriesParams),
+    /// Aroon (AROON)
+    Aroon(PeriodParams),
+    /// Aroon oscillator (AROONOSC)
+    AroonOsc(PeriodParams),
+    /// Money flow index (MFI)
+    Mfi(PeriodParams),
+    /// 1-day rate of change of a triple smooth exponential moving average
+    /// (TRIX)
+    Trix(PeriodSeriesParams),
+    /// Ultimate oscillator (ULTOSC)
+    UltOsc(UltimateOscillatorParams),
+    /// Directional movement index (DX)
+    Dx(PeriodParams),
+    /// Minus directional indicator (`MINUS_DI`)
+    MinusDi(PeriodParams),
+    /// Plus directional indicator (`PLUS_DI`)
+    PlusDi(PeriodParams),
+    /// Minus directional movement (`MINUS_DM`)
+    MinusDm(PeriodParams),
+    /// Plus directional movement (`PLUS_DM`)
+    PlusDm(PeriodParams),
+    /// Bollinger bands (BBANDS)
+    BBands(BBandsParams),
+    /// Midpoint of highest and lowest value (MIDPOINT)
+    Midpoint(PeriodSeriesParams),
+    /// Midpoint of highest high and lowest low (MIDPRICE)
+    MidPrice(PeriodParams),
+    /// Parabolic SAR (SAR)
+    Sar(SarParams),
+    /// True range (TRANGE)
+    Trange,
+    /// Average true range (ATR)
+    Atr(PeriodParams),
+    /// Normalized average true range (NATR)
+    Natr(PeriodParams),
+    /// Chaikin A/D line (AD)
+    Ad,
+    /// Chaikin A/D oscillator (ADOSC)
+    AdOsc(AdOscParams),
+    /// On balance volume (OBV)
+    Obv,
+    /// Hilbert transform instantaneous trendline (`HT_TRENDLINE`)
+    HtTrendline(SeriesParams),
+    /// Hilbert transform sine wave (`HT_SINE`)
+    HtSine(SeriesParams),
+    /// Hilbert transform trend vs cycle mode (`HT_TRENDMODE`)
+    HtTrendMode(SeriesParams),
+    /// Hilbert transform dominant cycle period (`HT_DCPERIOD`)
+    HtDcPeriod(SeriesParams),
+    /// Hilbert transform dominant cycle phase (`HT_DCPHASE`)
+    HtDcPhase(SeriesParams),
+    /// Hilbert transform phasor components (`HT_PHASOR`)
+    HtPhasor(SeriesParams),
+}
+
+impl TechnicalIndicatorFunction {
+    /// Return function name used by API
+    #[must_use]
+    pub fn function(&self) -> &'static str {
+        match self {
+            TechnicalIndicatorFunction::Sma(_) => "SMA",
+            TechnicalIndicatorFunction::Ema(_) => "EMA",
+            TechnicalIndicatorFunction::Wma(_) => "WMA",
+            TechnicalIndicatorFunction::Dema(_) => "DEMA",
+            TechnicalIndicatorFunction::Tema(_) => "TEMA",
+            TechnicalIndicatorFunction::Trima(_) => "TRIMA",
+            TechnicalIndicatorFunction::Kama(_) => "KAMA",
+            TechnicalIndicatorFunction::Mama(_) => "MAMA",
+            TechnicalIndicatorFunction::Vwap => "VWAP",
+            TechnicalIndicatorFunction::T3(_) => "T3",
+            TechnicalIndicatorFunction::Macd(_) => "MACD",
+            TechnicalIndicatorFunction::MacdExt(_) => "MACDEXT",
+            TechnicalIndicatorFunction::Stoch(_) => "STOCH",
+            TechnicalIndicatorFunction::StochF(_) => "STOCHF",
+            TechnicalIndicatorFunction::Rsi(_) => "RSI",
+            TechnicalIndicatorFunction::StochRsi(_) => "STOCHRSI",
+            TechnicalIndicatorFunction::Willr(_) => "WILLR",
+            TechnicalIndicatorFunction::Adx(_) => "ADX",
+            TechnicalIndicatorFunction::Adxr(_) => "ADXR",
+            TechnicalIndicatorFunction::Apo(_) => "APO",
+            TechnicalIndicatorFunction::Ppo(_) => "PPO",
+            TechnicalIndicatorFunction::Mom(_) => "MOM",
+            TechnicalIndicatorFunction::Bop => "BOP",
+            TechnicalIndicatorFunction::Cci(_) => "CCI",
+            TechnicalIndicatorFunction::Cmo(_) => "CMO",
+            TechnicalIndicatorFunction::Roc(_) => "ROC",
+            TechnicalIndicatorFunction::Rocr(_) => "ROCR",
+            TechnicalIndicatorFunction::Aroon(_) => "AROON",
+            TechnicalIndicatorFunction::AroonOsc(_) => "AROONOSC",
+            TechnicalIndicatorFunction::Mfi(_) => "MFI",
+            TechnicalIndicatorFunction::Trix(_) => "TRIX",
+            TechnicalIndicatorFunction::UltOsc(_) => "ULTOSC",
+            TechnicalIndicatorFunction::Dx(_) => "DX",
+            TechnicalIndicatorFunction::MinusDi(_) => "MINUS_DI",
+            TechnicalIndicatorFunction::PlusDi(_) => "PLUS_DI",
+            TechnicalIndicatorFunction::MinusDm(_) => "MINUS_DM",
+            TechnicalIndicatorFunction::PlusDm(_) => "PLUS_DM",
+            TechnicalIndicatorFunction::BBands(_) => "BBANDS",
+            TechnicalIndicatorFunction::Midpoint(_) => "MIDPOINT",
+            TechnicalIndicatorFunction::MidPrice(_) => "MIDPRICE",
+            TechnicalIndicatorFunction::Sar(_) => "SAR",
+            TechnicalIndicatorFunction::Trange => "TRANGE",
+            TechnicalIndicatorFunction::Atr(_) => "ATR",
+            TechnicalIndicatorFunction::Natr(_) => "NATR",
+            TechnicalIndicatorFunction::Ad => "AD",
+            TechnicalIndicatorFunction::AdOsc(_) => "ADOSC",
+            TechnicalIndicatorFunction::Obv => "OBV",
+            TechnicalIndicatorFunction::HtTrendline(_) => "HT_TRENDLINE",
+            TechnicalIndicatorFunction::HtSine(_) => "HT_SINE",
+            TechnicalIndicatorFunction::HtTrendMode(_) => "HT_TRENDMODE",
+            TechnicalIndicatorFunction::HtDcPeriod(_) => "HT_DCPERIOD",
+            TechnicalIndicatorFunction::HtDcPhase(_) => "HT_DCPHASE",
+            TechnicalIndicatorFunction::HtPhasor(_) => "HT_PHASOR",
+        }
+    }
+
+    /// Validate parameter of function for interval
+    ///
+    /// ```
+    /// use alpha_vantage::technical_indicator::{
+    ///     MacdParams, PeriodSeriesParams, SeriesType, TechnicalIndicatorFunction,
+    ///     TechnicalIndicatorInterval,
+    /// };
+    ///
+    /// let function = TechnicalIndicatorFunction::Sma(PeriodSeriesParams::new(0, SeriesType::Close));
+    /// assert!(
+    ///     function
+    ///         .validate(&TechnicalIndicatorInterval::Daily)
+    ///         .is_err()
+    /// );
+    ///
+    /// let function = TechnicalIndicatorFunction::Macd(
+    ///     MacdParams::new(SeriesType::Close)
+    ///         .fast_period(26)
+    ///         .slow_period(12),
+    /// );
+    /// assert!(
+    ///     function
+    ///         .validate(&TechnicalIndicatorInterval::Daily)
+    ///         .is_err()
+    /// );
+    ///
+    /// let function = TechnicalIndicatorFunction::Vwap;
+    /// assert!(
+    ///     function
+    ///         .validate(&TechnicalIndicatorInterval::Daily)
+    ///         .is_err()
+    /// );
+    /// assert!(
+    ///     function
+    ///         .validate(&TechnicalIndicatorInterval::FiveMin)
+    ///         .is_ok()
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    /// Raise `Error::InvalidParameter` if any parameter is invalid or function
+    /// does not support interval
+    pub fn validate(&self, interval: &TechnicalIndicatorInterval) -> Result<()> {
+        match self {
+            TechnicalIndicatorFunction::Sma(params)
+            | TechnicalIndicatorFunction::Ema(params)
+            | TechnicalIndicatorFunction::Wma(params)
+            | TechnicalIndicatorFunction::Dema(params)
+            | TechnicalIndicatorFunction::Tema(params)
+            | TechnicalIndicatorFunction::Trima(params)
+            | TechnicalIndicatorFunction::Kama(params)
+            | TechnicalIndicatorFunction::T3(params)
+            | TechnicalIndicatorFunction::Rsi(params)
+            | TechnicalIndicatorFunction::Mom(params)
+            | TechnicalIndicatorFunction::Cmo(params)
+            | TechnicalIndicatorFunction::Roc(params)
+            | TechnicalIndicatorFunction::Rocr(params)
+            | TechnicalIndicatorFunction::Trix(params)
+            | TechnicalIndicatorFunction::Midpoint(params) => params.validate(),
+            TechnicalIndicatorFunction::Willr(params)
+            | TechnicalIndicatorFunction::Adx(params)
+            | TechnicalIndicatorFunction::Adxr(params)
+            | TechnicalIndicatorFunction::Cci(params)
+            | TechnicalIndicatorFunction::Aroon(params)
+            | TechnicalIndicatorFunction::AroonOsc(params)
+            | TechnicalIndicatorFunction::Mfi(params)
+            | TechnicalIndicatorFunction::Dx(params)
+            | TechnicalIndicatorFunction::MinusDi(params)
+            | TechnicalIndicatorFunction::PlusDi(params)
+            | TechnicalIndicatorFunction::MinusDm(params)
+            | TechnicalIndicatorFunction::PlusDm(params)
+            | TechnicalIndicatorFunction::MidPrice(params)
+            | TechnicalIndicatorFunction::Atr(params)
+            | TechnicalIndicatorFunction::Natr(params) => params.validate(),
+            TechnicalIndicatorFunction::Mama(params) => params.validate(),
+            TechnicalIndicatorFunction::Macd(params) => params.validate(),
+            TechnicalIndicatorFunction::MacdExt(params) => params.validate(),
+            TechnicalIndicatorFunction::Stoch(params) => params.validate(),
+            TechnicalIndicatorFunction::StochF(params) => params.validate(),
+            TechnicalIndicatorFunction::StochRsi(params) => params.validate(),
+            TechnicalIndicatorFunction::Apo(params) | TechnicalIndicatorFunction::Ppo(params) => {
+                params.validate()
+            }
+            TechnicalIndicatorFunction::UltOsc(params) => params.validate(),
+            TechnicalIndicatorFunction::BBands(params) => params.validate(),
+            TechnicalIndicatorFunction::Sar(params) => params.validate(),
+            TechnicalIndicatorFunction::AdOsc(params) => params.validate(),
+            TechnicalIndicatorFunction::Vwap if !interval.is_intraday() => {
+                Err(Error::InvalidParameter(
+                    "VWAP only supports intraday interval".to_string(),
+                ))
+            }
+            TechnicalIndicatorFunction::HtTrendline(_)
+            | TechnicalIndicatorFunction::HtSine(_)
+            | TechnicalIndicatorFunction::HtTrendMode(_)
+            | TechnicalIndicatorFunction::HtDcPeriod(_)
+            | TechnicalIndicatorFunction::HtDcPhase(_)
+            | TechnicalIndicatorFunction::HtPhasor(_)
+            | TechnicalIndicatorFunction::Vwap
+            | TechnicalIndicatorFunction::Bop
+            | TechnicalIndicatorFunction::Trange
+            | TechnicalIndicatorFunction::Ad
+            | TechnicalIndicatorFunction::Obv => Ok(()),
+        }
+    }
+
+    fn params(&self) -> Params {
+        match self {
+            TechnicalIndicatorFunction::Sma(params)
+            | TechnicalIndicatorFunction::Ema(params)
+            | TechnicalIndicatorFunction::Wma(params)
+            | TechnicalIndicatorFunction::Dema(params)
+            | TechnicalIndicatorFunction::Tema(params)
+            | TechnicalIndicatorFunction::Trima(params)
+            | TechnicalIndicatorFunction::Kama(params)
+            | TechnicalIndicatorFunction::T3(params)
+            | TechnicalIndicatorFunction::Rsi(params)
+            | TechnicalIndicatorFunction::Mom(params)
+            | TechnicalIndicatorFunction::Cmo(params)
+            | TechnicalIndicatorFunction::Roc(params)
+            | TechnicalIndicatorFunction::Rocr(params)
+            | TechnicalIndicatorFunction::Trix(params)
+            | TechnicalIndicatorFunction::Midpoint(params) => params.params(),
+            TechnicalIndicatorFunction::Willr(params)
+            | TechnicalIndicatorFunction::Adx(params)
+            | TechnicalIndicatorFunction::Adxr(params)
+            | TechnicalIndicatorFunction::Cci(params)
+            | TechnicalIndicatorFunction::Aroon(params)
+            | TechnicalIndicatorFunction::AroonOsc(params)
+            | TechnicalIndicatorFunction::Mfi(params)
+            | TechnicalIndicatorFunction::Dx(params)
+            | TechnicalIndicatorFunction::MinusDi(params)
+            | TechnicalIndicatorFunction::PlusDi(params)
+            | TechnicalIndicatorFunction::MinusDm(params)
+            | TechnicalIndicatorFunction::PlusDm(params)
+            | TechnicalIndicatorFunction::MidPrice(params)
+            | TechnicalIndicatorFunction::Atr(params)
+            | TechnicalIndicatorFunction::Natr(params) => params.params(),
+            TechnicalIndicatorFunction::HtTrendline(params)
+            | TechnicalIndicatorFunction::HtSine(params)
+            | TechnicalIndicatorFunction::HtTrendMode(params)
+            | TechnicalIndicatorFunction::HtDcPeriod(params)
+            | TechnicalIndicatorFunction::HtDcPhase(params)
+            | TechnicalIndicatorFunction::HtPhasor(params) => params.params(),
+            TechnicalIndicatorFunction::Mama(params) => params.params(),
+            TechnicalIndicatorFunction::Macd(params) => params.params(),
+            TechnicalIndicatorFunction::MacdExt(params) => params.params(),
+            TechnicalIndicatorFunction::Stoch(params) => params.params(),
+            TechnicalIndicatorFunction::StochF(params) => params.params(),
+            TechnicalIndicatorFunction::StochRsi(params) => params.params(),
+            TechnicalIndicatorFunction::Apo(params) | TechnicalIndicatorFunction::Ppo(params) => {
+                params.params()
+            }
+            TechnicalIndicatorFunction::UltOsc(params) => params.params(),
+            TechnicalIndicatorFunction::BBands(params) => params.params(),
+            TechnicalIndicatorFunction::Sar(params) => params.params(),
+            TechnicalIndicatorFunction::AdOsc(params) => params.params(),
+            TechnicalIndicatorFunction::Vwap
+            | TechnicalIndicatorFunction::Bop
+            | TechnicalIndicatorFunction::Trange
+            | TechnicalIndicatorFunction::Ad
+            | TechnicalIndicatorFunction::Obv => Vec::new(),
+        }
+    }
+}
@@ -1,11 +1,22 @@
 //! Module which contains some common trait implementation for `Vec<Data>`
+//!
+//! Data returned by `data()` of series is sorted chronologically so all
+//! operation are performed using binary search. Vector built or modified
+//! manually needs to be sorted in ascending order of time before using these
+//! operation otherwise returned value is unspecified
 
-use crate::error::Result;
+use crate::candle::Candle;
+use crate::error::{Error, Result};
 
 /// trait which helps for performing some common operation on `Vec<Data>` which
-/// have given period
+/// have given period. Implemented for vector of any `Candle`
+///
+/// Data needs to be sorted in ascending order of time. `find`, `latest`,
+/// `latest_n` and `range` do not check order and return unspecified value for
+/// unsorted data
 pub trait FindData: IntoIterator {
-    /// Find a data with a given time as a input return none if no data found
+    /// Find a data with a given time as a input return none if no data found.
+    /// Data needs to be sorted in ascending order of time
     fn find(&self, time: &str) -> Option<&<Self as IntoIterator>::Item>;
     /// Return a data which is of latest time period
     fn latest(&self) -> <Self as IntoIterator>::Item;
@@ -13,4 +24,62 @@ pub trait FindData: IntoIterator {
     /// # Errors
     /// If n is greater than no of data
     fn latest_n(&self, n: usize) -> Result<Vec<&<Self as IntoIterator>::Item>>;
+    /// Return data in half-open range `[start, end)` i.e. data whose time is
+    /// greater than or equal to start and less than end. `end` is exclusive so
+    /// data with time equal to `end` is not returned. Data needs to be sorted
+    /// in ascending order of time
+    ///
+    /// ```
+    /// use alpha_vantage::vec_trait::FindData;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = alpha_vantage::mock::MockClient::from_directory("tests/fixtures").unwrap();
+    ///     let api = alpha_vantage::set_api("demo", client);
+    ///     let stock = api
+    ///         .stock_time(alpha_vantage::stock_time::StockFunction::Weekly, "MSFT")
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     let data = stock.data().range("2023-09-01", "2023-10-01");
+    ///     assert!(!data.is_empty());
+    ///     assert!(data.windows(2).all(|pair| pair[0].time() < pair[1].time()));
+    ///     assert!(data.iter().all(|value| value.time().starts_with("2023-09")));
+    ///
+    ///     let first = data[0].time();
+    ///     assert!(stock.data().range(first, first).is_empty());
+    ///     assert_eq!(stock.data().range(first, data[1].time()).len(), 1);
+    /// }
+    /// ```
+    fn range(&self, start: &str, end: &str) -> &[<Self as IntoIterator>::Item];
+}
+
+// Vector needs to be sorted by `Candle::time` in ascending order which is the
+// case for data returned by `data()` of series
+impl<T> FindData for Vec<T>
+where
+    T: Candle + Clone + Default,
+{
+    fn find(&self, time: &str) -> Option<&<Self as IntoIterator>::Item> {
+        self.binary_search_by(|data| data.time().cmp(time))
+            .ok()
+            .map(|index| &self[index])
+    }
+
+    fn latest(&self) -> <Self as IntoIterator>::Item {
+        self.last().cloned().unwrap_or_default()
+    }
+
+    fn latest_n(&self, n: usize) -> Result<Vec<&<Self as IntoIterator>::Item>> {
+        if n > self.len() {
+            return Err(Error::DesiredNumberOfDataNotPresent(self.len()));
+        }
+        Ok(self[self.len() - n..].iter().rev().collect())
+    }
+
+    fn range(&self, start: &str, end: &str) -> &[<Self as IntoIterator>::Item] {
+        let start_index = self.partition_point(|data| data.time() < start);
+        let end_index = self.partition_point(|data| data.time() < end);
+        &self[start_index..end_index.max(start_index)]
+    }
 }
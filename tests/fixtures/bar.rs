@@ -0,0 +1,36 @@
+// Candle used by local indicator doctests which stores time, open, high, low,
+// close and volume
+pub struct Bar(
+    pub &'static str,
+    pub f64,
+    pub f64,
+    pub f64,
+    pub f64,
+    pub Option<f64>,
+);
+
+impl alpha_vantage::candle::Candle for Bar {
+    fn time(&self) -> &str {
+        self.0
+    }
+
+    fn open(&self) -> f64 {
+        self.1
+    }
+
+    fn high(&self) -> f64 {
+        self.2
+    }
+
+    fn low(&self) -> f64 {
+        self.3
+    }
+
+    fn close(&self) -> f64 {
+        self.4
+    }
+
+    fn volume(&self) -> Option<f64> {
+        self.5
+    }
+}